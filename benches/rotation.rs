@@ -0,0 +1,67 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use math::*;
+
+const VECTORS: usize = 10_000;
+const ROUNDS: u32 = 200;
+
+fn sandwich(rotation: Quaternion<f32>, vector: Vec3<f32>) -> Vec3<f32> {
+    let pure = Quaternion::new(0.0, vector);
+    (rotation * pure * rotation.inverse()).vector
+}
+
+fn measure(name: &str, mut f: impl FnMut()) -> Duration {
+    f();
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{:<24} {:>10.2?} per round ({} vectors)",
+        name,
+        elapsed / ROUNDS,
+        VECTORS
+    );
+    elapsed
+}
+
+fn main() {
+    let rotation = Quaternion::from_axis_angle(
+        Unit::new_normalize(Vec3::new(1.0, 2.0, 3.0)).unwrap(),
+        Rad(0.7),
+    );
+    let source: Vec<_> = (0..VECTORS)
+        .map(|i| Vec3::new(i as f32, (i % 7) as f32, 1.0))
+        .collect();
+    let mut vectors = source.clone();
+
+    let slow = measure("sandwich product", || {
+        for (out, vector) in vectors.iter_mut().zip(&source) {
+            *out = sandwich(black_box(rotation), *vector);
+        }
+        black_box(&vectors);
+    });
+
+    let fast = measure("Mul<Vec3>", || {
+        for (out, vector) in vectors.iter_mut().zip(&source) {
+            *out = black_box(rotation) * *vector;
+        }
+        black_box(&vectors);
+    });
+
+    let batch = measure("rotate_slice", || {
+        vectors.copy_from_slice(&source);
+        black_box(rotation).rotate_slice(&mut vectors);
+        black_box(&vectors);
+    });
+
+    println!(
+        "speedup: Mul<Vec3> {:.2}x, rotate_slice {:.2}x",
+        slow.as_secs_f64() / fast.as_secs_f64(),
+        slow.as_secs_f64() / batch.as_secs_f64()
+    );
+}
@@ -0,0 +1,280 @@
+use super::base::Float;
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Rad
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
+pub struct Rad<T: Float>(pub T);
+
+impl<T> Rad<T>
+where
+    T: Float,
+{
+    pub fn full_turn() -> Self {
+        Rad(T::tau())
+    }
+
+    pub fn half_turn() -> Self {
+        Rad(T::pi())
+    }
+
+    pub fn quarter_turn() -> Self {
+        Rad(T::frac_pi_2())
+    }
+
+    // Wraps into [0, 2 * PI).
+    pub fn normalize(self) -> Self {
+        let rem = self.0 % T::tau();
+        let wrapped = if rem < T::zero() { rem + T::tau() } else { rem };
+        // Adding tau to a tiny negative remainder can round up to tau itself.
+        Rad(if wrapped >= T::tau() {
+            T::zero()
+        } else {
+            wrapped
+        })
+    }
+
+    // Wraps into [-PI, PI).
+    pub fn normalize_signed(self) -> Self {
+        Rad((self + Self::half_turn()).normalize().0 - T::pi())
+    }
+
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+
+    pub fn tan(self) -> T {
+        self.0.tan()
+    }
+
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.sin_cos()
+    }
+
+    pub fn asin(ratio: T) -> Self {
+        Rad(ratio.asin())
+    }
+
+    pub fn acos(ratio: T) -> Self {
+        Rad(ratio.acos())
+    }
+
+    pub fn atan(ratio: T) -> Self {
+        Rad(ratio.atan())
+    }
+
+    pub fn atan2(y: T, x: T) -> Self {
+        Rad(y.atan2(x))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Deg
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
+pub struct Deg<T: Float>(pub T);
+
+impl<T> Deg<T>
+where
+    T: Float,
+{
+    pub fn full_turn() -> Self {
+        Rad::full_turn().into()
+    }
+
+    pub fn half_turn() -> Self {
+        Rad::half_turn().into()
+    }
+
+    pub fn quarter_turn() -> Self {
+        Rad::quarter_turn().into()
+    }
+
+    // Wraps into [0, 360).
+    pub fn normalize(self) -> Self {
+        let full = Self::full_turn().0;
+        let rem = self.0 % full;
+        let wrapped = if rem < T::zero() { rem + full } else { rem };
+        Deg(if wrapped >= full { T::zero() } else { wrapped })
+    }
+
+    // Wraps into [-180, 180).
+    pub fn normalize_signed(self) -> Self {
+        let half = Self::half_turn();
+        Deg((self + half).normalize().0 - half.0)
+    }
+
+    pub fn sin(self) -> T {
+        Rad::from(self).sin()
+    }
+
+    pub fn cos(self) -> T {
+        Rad::from(self).cos()
+    }
+
+    pub fn tan(self) -> T {
+        Rad::from(self).tan()
+    }
+
+    pub fn sin_cos(self) -> (T, T) {
+        Rad::from(self).sin_cos()
+    }
+
+    pub fn asin(ratio: T) -> Self {
+        Rad::asin(ratio).into()
+    }
+
+    pub fn acos(ratio: T) -> Self {
+        Rad::acos(ratio).into()
+    }
+
+    pub fn atan(ratio: T) -> Self {
+        Rad::atan(ratio).into()
+    }
+
+    pub fn atan2(y: T, x: T) -> Self {
+        Rad::atan2(y, x).into()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Conversions
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T> From<Deg<T>> for Rad<T>
+where
+    T: Float,
+{
+    fn from(value: Deg<T>) -> Self {
+        Rad(value.0.to_radians())
+    }
+}
+
+impl<T> From<Rad<T>> for Deg<T>
+where
+    T: Float,
+{
+    fn from(value: Rad<T>) -> Self {
+        Deg(value.0.to_degrees())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Operators
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_angle_ops {
+    ($angle:ident) => {
+        impl<T: Float> Add for $angle<T> {
+            type Output = $angle<T>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $angle(self.0 + rhs.0)
+            }
+        }
+
+        impl<T: Float> Sub for $angle<T> {
+            type Output = $angle<T>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $angle(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: Float> Neg for $angle<T> {
+            type Output = $angle<T>;
+
+            fn neg(self) -> Self::Output {
+                $angle(-self.0)
+            }
+        }
+
+        impl<T: Float> Mul<T> for $angle<T> {
+            type Output = $angle<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $angle(self.0 * rhs)
+            }
+        }
+
+        impl<T: Float> Div<T> for $angle<T> {
+            type Output = $angle<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $angle(self.0 / rhs)
+            }
+        }
+
+        // Ratio between two angles.
+        impl<T: Float> Div for $angle<T> {
+            type Output = T;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                self.0 / rhs.0
+            }
+        }
+
+        impl<T: Float> Rem for $angle<T> {
+            type Output = $angle<T>;
+
+            fn rem(self, rhs: Self) -> Self::Output {
+                $angle(self.0 % rhs.0)
+            }
+        }
+
+        impl<T: Float> AddAssign for $angle<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<T: Float> SubAssign for $angle<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl<T: Float> MulAssign<T> for $angle<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                self.0 *= rhs;
+            }
+        }
+
+        impl<T: Float> DivAssign<T> for $angle<T> {
+            fn div_assign(&mut self, rhs: T) {
+                self.0 = self.0 / rhs;
+            }
+        }
+    };
+}
+
+impl_angle_ops!(Rad);
+impl_angle_ops!(Deg);
+
+impl<T> Display for Rad<T>
+where
+    T: Float,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} rad", self.0)
+    }
+}
+
+impl<T> Display for Deg<T>
+where
+    T: Float,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}°", self.0)
+    }
+}
@@ -0,0 +1,463 @@
+use super::angle::{Deg, Rad};
+use super::base::Float;
+use super::dual_quaternion::DualQuaternion;
+use super::matrix::{Mat2, Mat3, Mat4};
+use super::quaternion::Quaternion;
+use super::unit::Unit;
+use super::vector::{Vec2, Vec3, Vec4};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Traits
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Equal when the absolute difference is within epsilon. Good near zero, too strict for large
+// magnitudes.
+pub trait AbsDiffEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    fn abs_diff_ne(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        !self.abs_diff_eq(other, epsilon)
+    }
+}
+
+// Equal when the difference is within max_relative of the larger magnitude. The absolute epsilon
+// still decides comparisons close to zero, where a relative tolerance shrinks to nothing.
+pub trait RelativeEq: AbsDiffEq {
+    fn default_max_relative() -> Self::Epsilon;
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    fn relative_ne(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        !self.relative_eq(other, epsilon, max_relative)
+    }
+}
+
+// Equal when no more than max_ulps representable floats lie between the two values. The
+// absolute epsilon again covers values around zero and of opposite sign.
+pub trait UlpsEq: AbsDiffEq {
+    fn default_max_ulps() -> u32;
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    fn ulps_ne(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        !self.ulps_eq(other, epsilon, max_ulps)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Comparisons
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Builders behind the comparison macros, so each tolerance can be overridden by name and the
+// rest keep their defaults.
+pub struct AbsDiff<T: AbsDiffEq + ?Sized> {
+    pub epsilon: T::Epsilon,
+}
+
+impl<T: AbsDiffEq + ?Sized> Default for AbsDiff<T> {
+    fn default() -> Self {
+        Self {
+            epsilon: T::default_epsilon(),
+        }
+    }
+}
+
+impl<T: AbsDiffEq + ?Sized> AbsDiff<T> {
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Self {
+        Self { epsilon }
+    }
+
+    pub fn eq(&self, left: &T, right: &T) -> bool {
+        left.abs_diff_eq(right, self.epsilon)
+    }
+
+    pub fn ne(&self, left: &T, right: &T) -> bool {
+        left.abs_diff_ne(right, self.epsilon)
+    }
+}
+
+pub struct Relative<T: RelativeEq + ?Sized> {
+    pub epsilon: T::Epsilon,
+    pub max_relative: T::Epsilon,
+}
+
+impl<T: RelativeEq + ?Sized> Default for Relative<T> {
+    fn default() -> Self {
+        Self {
+            epsilon: T::default_epsilon(),
+            max_relative: T::default_max_relative(),
+        }
+    }
+}
+
+impl<T: RelativeEq + ?Sized> Relative<T> {
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Self {
+        Self { epsilon, ..self }
+    }
+
+    pub fn max_relative(self, max_relative: T::Epsilon) -> Self {
+        Self {
+            max_relative,
+            ..self
+        }
+    }
+
+    pub fn eq(&self, left: &T, right: &T) -> bool {
+        left.relative_eq(right, self.epsilon, self.max_relative)
+    }
+
+    pub fn ne(&self, left: &T, right: &T) -> bool {
+        left.relative_ne(right, self.epsilon, self.max_relative)
+    }
+}
+
+pub struct Ulps<T: UlpsEq + ?Sized> {
+    pub epsilon: T::Epsilon,
+    pub max_ulps: u32,
+}
+
+impl<T: UlpsEq + ?Sized> Default for Ulps<T> {
+    fn default() -> Self {
+        Self {
+            epsilon: T::default_epsilon(),
+            max_ulps: T::default_max_ulps(),
+        }
+    }
+}
+
+impl<T: UlpsEq + ?Sized> Ulps<T> {
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Self {
+        Self { epsilon, ..self }
+    }
+
+    pub fn max_ulps(self, max_ulps: u32) -> Self {
+        Self { max_ulps, ..self }
+    }
+
+    pub fn eq(&self, left: &T, right: &T) -> bool {
+        left.ulps_eq(right, self.epsilon, self.max_ulps)
+    }
+
+    pub fn ne(&self, left: &T, right: &T) -> bool {
+        left.ulps_ne(right, self.epsilon, self.max_ulps)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Macros
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// abs_diff_eq!(a, b) or abs_diff_eq!(a, b, epsilon = 1e-6)
+#[macro_export]
+macro_rules! abs_diff_eq {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::approx::AbsDiff::default()$(.$opt($val))*.eq(&$left, &$right)
+    };
+}
+
+// relative_eq!(a, b) with optional epsilon = .. and max_relative = ..
+#[macro_export]
+macro_rules! relative_eq {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::approx::Relative::default()$(.$opt($val))*.eq(&$left, &$right)
+    };
+}
+
+// ulps_eq!(a, b) with optional epsilon = .. and max_ulps = ..
+#[macro_export]
+macro_rules! ulps_eq {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::approx::Ulps::default()$(.$opt($val))*.eq(&$left, &$right)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx {
+    ($name:literal, $cmp:ident, $op:ident, $left:expr, $right:expr $(, $opt:ident = $val:expr)*) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::approx::$cmp::default()$(.$opt($val))*.$op(left, right) {
+                    panic!(
+                        "assertion failed: `{}!({}, {})`\n  left: `{:?}`\n right: `{:?}`",
+                        $name,
+                        stringify!($left),
+                        stringify!($right),
+                        left,
+                        right,
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::__assert_approx!("assert_abs_diff_eq", AbsDiff, eq, $left, $right $(, $opt = $val)*)
+    };
+}
+
+#[macro_export]
+macro_rules! assert_abs_diff_ne {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::__assert_approx!("assert_abs_diff_ne", AbsDiff, ne, $left, $right $(, $opt = $val)*)
+    };
+}
+
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::__assert_approx!("assert_relative_eq", Relative, eq, $left, $right $(, $opt = $val)*)
+    };
+}
+
+#[macro_export]
+macro_rules! assert_relative_ne {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::__assert_approx!("assert_relative_ne", Relative, ne, $left, $right $(, $opt = $val)*)
+    };
+}
+
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::__assert_approx!("assert_ulps_eq", Ulps, eq, $left, $right $(, $opt = $val)*)
+    };
+}
+
+#[macro_export]
+macro_rules! assert_ulps_ne {
+    ($left:expr, $right:expr $(, $opt:ident = $val:expr)* $(,)?) => {
+        $crate::__assert_approx!("assert_ulps_ne", Ulps, ne, $left, $right $(, $opt = $val)*)
+    };
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Scalars
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_approx_float {
+    ($float:ty, $signed:ty, $unsigned:ty) => {
+        impl AbsDiffEq for $float {
+            type Epsilon = $float;
+
+            fn default_epsilon() -> Self::Epsilon {
+                <$float>::EPSILON
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                // Equal infinities have a NaN difference, so check for exact equality first.
+                self == other || (self - other).abs() <= epsilon
+            }
+        }
+
+        impl RelativeEq for $float {
+            fn default_max_relative() -> Self::Epsilon {
+                <$float>::EPSILON
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                if self == other {
+                    return true;
+                }
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+
+                let diff = (self - other).abs();
+                if diff <= epsilon {
+                    return true;
+                }
+                diff <= self.abs().max(other.abs()) * max_relative
+            }
+        }
+
+        impl UlpsEq for $float {
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, epsilon) {
+                    return true;
+                }
+                if self.is_nan()
+                    || other.is_nan()
+                    || self.is_sign_negative() != other.is_sign_negative()
+                {
+                    return false;
+                }
+
+                // Same-signed floats order like their bit patterns, so the integer distance
+                // counts the representable values in between.
+                let diff = (self.to_bits() as $signed).wrapping_sub(other.to_bits() as $signed);
+                diff.unsigned_abs() <= max_ulps as $unsigned
+            }
+        }
+    };
+}
+
+impl_approx_float!(f32, i32, u32);
+impl_approx_float!(f64, i64, u64);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Compound Types
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Compound types compare field by field with the same tolerances. Every field is either a
+// scalar or another compound type over the same scalar.
+macro_rules! impl_approx_fields {
+    ($ty:ident { $($field:tt),+ }) => {
+        impl<T> AbsDiffEq for $ty<T>
+        where
+            T: Float + AbsDiffEq,
+        {
+            type Epsilon = T::Epsilon;
+
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                $(self.$field.abs_diff_eq(&other.$field, epsilon))&&+
+            }
+        }
+
+        impl<T> RelativeEq for $ty<T>
+        where
+            T: Float + RelativeEq,
+        {
+            fn default_max_relative() -> Self::Epsilon {
+                T::default_max_relative()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+            }
+        }
+
+        impl<T> UlpsEq for $ty<T>
+        where
+            T: Float + UlpsEq,
+        {
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+            }
+        }
+    };
+}
+
+impl_approx_fields!(Rad { 0 });
+impl_approx_fields!(Deg { 0 });
+
+impl_approx_fields!(Vec2 { x, y });
+impl_approx_fields!(Vec3 { x, y, z });
+impl_approx_fields!(Vec4 { x, y, z, w });
+
+impl_approx_fields!(Mat2 { m00, m01, m10, m11 });
+impl_approx_fields!(Mat3 {
+    m00,
+    m01,
+    m02,
+    m10,
+    m11,
+    m12,
+    m20,
+    m21,
+    m22
+});
+impl_approx_fields!(Mat4 {
+    m00,
+    m01,
+    m02,
+    m03,
+    m10,
+    m11,
+    m12,
+    m13,
+    m20,
+    m21,
+    m22,
+    m23,
+    m30,
+    m31,
+    m32,
+    m33
+});
+
+impl_approx_fields!(Quaternion { scalar, vector });
+impl_approx_fields!(DualQuaternion { real, dual });
+
+impl<V> AbsDiffEq for Unit<V>
+where
+    V: AbsDiffEq,
+{
+    type Epsilon = V::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        V::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.as_ref().abs_diff_eq(other.as_ref(), epsilon)
+    }
+}
+
+impl<V> RelativeEq for Unit<V>
+where
+    V: RelativeEq,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        V::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.as_ref()
+            .relative_eq(other.as_ref(), epsilon, max_relative)
+    }
+}
+
+impl<V> UlpsEq for Unit<V>
+where
+    V: UlpsEq,
+{
+    fn default_max_ulps() -> u32 {
+        V::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.as_ref().ulps_eq(other.as_ref(), epsilon, max_ulps)
+    }
+}
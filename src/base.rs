@@ -1,31 +1,101 @@
 use std::{
-    cmp::PartialEq,
+    cmp::{PartialEq, PartialOrd},
     fmt::Display,
-    ops::{AddAssign, MulAssign, SubAssign},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
 };
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Constants
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[deprecated(note = "convert with `Deg` and `Rad` instead")]
 pub const RADIAN: f64 = 57.2957795131;
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Traits
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub trait Float: Display + Copy + AddAssign + SubAssign + MulAssign + PartialEq {
+// Base of the scalar hierarchy. Anything vectors and matrices can be built from: integers of any
+// width and sign, and floats.
+pub trait Number:
+    Display
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+// Numbers that can be negated: the signed integers and floats.
+pub trait Signed: Number + Neg<Output = Self> {
+    fn neg_one() -> Self;
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+// Real numbers. Everything that needs roots, trigonometry or division with a fractional result
+// is gated behind this.
+pub trait Float: Signed {
     fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
     fn sin(self) -> Self;
     fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn ln(self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn recip(self) -> Self;
     fn sin_cos(self) -> (Self, Self)
     where
         Self: Sized;
-    fn acos(self) -> Self;
-    fn one() -> Self;
-    fn zero() -> Self;
-    fn neg_one() -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn log(self, base: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+    fn is_sign_negative(self) -> bool;
+
     fn one_half() -> Self;
+    fn two() -> Self;
+    fn epsilon() -> Self;
+    fn infinity() -> Self;
+    fn neg_infinity() -> Self;
+    fn nan() -> Self;
+    fn min_value() -> Self;
+    fn max_value() -> Self;
+    fn pi() -> Self;
+    fn tau() -> Self;
+    fn frac_pi_2() -> Self;
+    fn frac_pi_4() -> Self;
 
     fn to_degrees(self) -> Self;
     fn to_radians(self) -> Self;
@@ -34,11 +104,67 @@ pub trait Float: Display + Copy + AddAssign + SubAssign + MulAssign + PartialEq
     fn aprox_eq(self, other: Self, within: Self) -> bool;
 }
 
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Number
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_number {
+    ($($ty:ty),+) => {
+        $(
+            impl Number for $ty {
+                fn zero() -> Self {
+                    0 as $ty
+                }
+
+                fn one() -> Self {
+                    1 as $ty
+                }
+            }
+        )+
+    };
+}
+
+impl_number!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Signed
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_signed {
+    ($($ty:ty),+) => {
+        $(
+            impl Signed for $ty {
+                fn neg_one() -> Self {
+                    -1 as $ty
+                }
+
+                fn abs(self) -> Self {
+                    <$ty>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$ty>::signum(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Float
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
 impl Float for f32 {
     fn sqrt(self) -> Self {
         f32::sqrt(self)
     }
 
+    fn cbrt(self) -> Self {
+        f32::cbrt(self)
+    }
+
     fn sin(self) -> Self {
         f32::sin(self)
     }
@@ -47,30 +173,182 @@ impl Float for f32 {
         f32::cos(self)
     }
 
-    fn sin_cos(self) -> (Self, Self) {
-        f32::sin_cos(self)
+    fn tan(self) -> Self {
+        f32::tan(self)
+    }
+
+    fn asin(self) -> Self {
+        f32::asin(self)
     }
 
     fn acos(self) -> Self {
         f32::acos(self)
     }
 
-    fn one() -> Self {
-        1.0f32
+    fn atan(self) -> Self {
+        f32::atan(self)
+    }
+
+    fn sinh(self) -> Self {
+        f32::sinh(self)
+    }
+
+    fn cosh(self) -> Self {
+        f32::cosh(self)
+    }
+
+    fn tanh(self) -> Self {
+        f32::tanh(self)
+    }
+
+    fn exp(self) -> Self {
+        f32::exp(self)
+    }
+
+    fn exp2(self) -> Self {
+        f32::exp2(self)
+    }
+
+    fn ln(self) -> Self {
+        f32::ln(self)
+    }
+
+    fn log2(self) -> Self {
+        f32::log2(self)
     }
 
-    fn zero() -> Self {
-        0.0f32
+    fn log10(self) -> Self {
+        f32::log10(self)
     }
 
-    fn neg_one() -> Self {
-        -1.0f32
+    fn floor(self) -> Self {
+        f32::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        f32::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        f32::round(self)
+    }
+
+    fn trunc(self) -> Self {
+        f32::trunc(self)
+    }
+
+    fn fract(self) -> Self {
+        f32::fract(self)
+    }
+
+    fn recip(self) -> Self {
+        f32::recip(self)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        f32::sin_cos(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        f32::atan2(self, other)
+    }
+
+    fn log(self, base: Self) -> Self {
+        f32::log(self, base)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        f32::powf(self, n)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        f32::hypot(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        f32::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        f32::max(self, other)
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        f32::copysign(self, sign)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        f32::powi(self, n)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        f32::mul_add(self, a, b)
+    }
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+
+    fn is_infinite(self) -> bool {
+        f32::is_infinite(self)
+    }
+
+    fn is_sign_negative(self) -> bool {
+        f32::is_sign_negative(self)
     }
 
     fn one_half() -> Self {
         0.5f32
     }
 
+    fn two() -> Self {
+        2.0f32
+    }
+
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+
+    fn infinity() -> Self {
+        f32::INFINITY
+    }
+
+    fn neg_infinity() -> Self {
+        f32::NEG_INFINITY
+    }
+
+    fn nan() -> Self {
+        f32::NAN
+    }
+
+    fn min_value() -> Self {
+        f32::MIN
+    }
+
+    fn max_value() -> Self {
+        f32::MAX
+    }
+
+    fn pi() -> Self {
+        std::f32::consts::PI
+    }
+
+    fn tau() -> Self {
+        std::f32::consts::TAU
+    }
+
+    fn frac_pi_2() -> Self {
+        std::f32::consts::FRAC_PI_2
+    }
+
+    fn frac_pi_4() -> Self {
+        std::f32::consts::FRAC_PI_4
+    }
+
     fn to_degrees(self) -> Self {
         f32::to_degrees(self)
     }
@@ -84,7 +362,7 @@ impl Float for f32 {
     }
 
     fn aprox_eq(self, other: Self, within: Self) -> bool {
-        (self - other) <= within
+        (self - other).abs() <= within
     }
 }
 
@@ -93,6 +371,10 @@ impl Float for f64 {
         f64::sqrt(self)
     }
 
+    fn cbrt(self) -> Self {
+        f64::cbrt(self)
+    }
+
     fn sin(self) -> Self {
         f64::sin(self)
     }
@@ -101,30 +383,182 @@ impl Float for f64 {
         f64::cos(self)
     }
 
-    fn sin_cos(self) -> (Self, Self) {
-        f64::sin_cos(self)
+    fn tan(self) -> Self {
+        f64::tan(self)
+    }
+
+    fn asin(self) -> Self {
+        f64::asin(self)
     }
 
     fn acos(self) -> Self {
         f64::acos(self)
     }
 
-    fn one() -> Self {
-        1.0f64
+    fn atan(self) -> Self {
+        f64::atan(self)
+    }
+
+    fn sinh(self) -> Self {
+        f64::sinh(self)
+    }
+
+    fn cosh(self) -> Self {
+        f64::cosh(self)
+    }
+
+    fn tanh(self) -> Self {
+        f64::tanh(self)
+    }
+
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+
+    fn exp2(self) -> Self {
+        f64::exp2(self)
+    }
+
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+
+    fn log2(self) -> Self {
+        f64::log2(self)
+    }
+
+    fn log10(self) -> Self {
+        f64::log10(self)
+    }
+
+    fn floor(self) -> Self {
+        f64::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        f64::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        f64::round(self)
+    }
+
+    fn trunc(self) -> Self {
+        f64::trunc(self)
+    }
+
+    fn fract(self) -> Self {
+        f64::fract(self)
+    }
+
+    fn recip(self) -> Self {
+        f64::recip(self)
     }
 
-    fn zero() -> Self {
-        0.0f64
+    fn sin_cos(self) -> (Self, Self) {
+        f64::sin_cos(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        f64::atan2(self, other)
+    }
+
+    fn log(self, base: Self) -> Self {
+        f64::log(self, base)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        f64::powf(self, n)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        f64::hypot(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        f64::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        f64::max(self, other)
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        f64::copysign(self, sign)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        f64::powi(self, n)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        f64::mul_add(self, a, b)
+    }
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
     }
 
-    fn neg_one() -> Self {
-        -1.0f64
+    fn is_infinite(self) -> bool {
+        f64::is_infinite(self)
+    }
+
+    fn is_sign_negative(self) -> bool {
+        f64::is_sign_negative(self)
     }
 
     fn one_half() -> Self {
         0.5f64
     }
 
+    fn two() -> Self {
+        2.0f64
+    }
+
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+
+    fn infinity() -> Self {
+        f64::INFINITY
+    }
+
+    fn neg_infinity() -> Self {
+        f64::NEG_INFINITY
+    }
+
+    fn nan() -> Self {
+        f64::NAN
+    }
+
+    fn min_value() -> Self {
+        f64::MIN
+    }
+
+    fn max_value() -> Self {
+        f64::MAX
+    }
+
+    fn pi() -> Self {
+        std::f64::consts::PI
+    }
+
+    fn tau() -> Self {
+        std::f64::consts::TAU
+    }
+
+    fn frac_pi_2() -> Self {
+        std::f64::consts::FRAC_PI_2
+    }
+
+    fn frac_pi_4() -> Self {
+        std::f64::consts::FRAC_PI_4
+    }
+
     fn to_degrees(self) -> Self {
         f64::to_degrees(self)
     }
@@ -138,6 +572,6 @@ impl Float for f64 {
     }
 
     fn aprox_eq(self, other: Self, within: Self) -> bool {
-        (self - other) <= within
+        (self - other).abs() <= within
     }
 }
@@ -0,0 +1,265 @@
+use std::cmp::PartialEq;
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::FloatVectorOps;
+
+use super::base::Float;
+use super::matrix::Mat4;
+use super::quaternion::Quaternion;
+use super::unit::Unit;
+use super::vector::Vec3;
+
+// Rigid transform stored as real + dual * epsilon, with epsilon^2 = 0. The real part is the
+// rotation and the dual part is half the translation multiplied onto it.
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
+pub struct DualQuaternion<T: Float> {
+    pub real: Quaternion<T>,
+    pub dual: Quaternion<T>,
+}
+
+impl<T> DualQuaternion<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: PartialOrd,
+{
+    pub fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        Self { real, dual }
+    }
+
+    pub fn identity() -> Self {
+        Self::new(Quaternion::identity(), Self::zero_quaternion())
+    }
+
+    fn zero_quaternion() -> Quaternion<T> {
+        Quaternion::new(T::zero(), Vec3::new(T::zero(), T::zero(), T::zero()))
+    }
+
+    // The rotation is applied first, then the translation.
+    pub fn from_rotation_translation(rotation: Unit<Quaternion<T>>, translation: Vec3<T>) -> Self {
+        let rotation = rotation.into_inner();
+        let dual = Quaternion::new(T::zero(), translation) * rotation * T::one_half();
+        Self::new(rotation, dual)
+    }
+
+    pub fn from_translation(translation: Vec3<T>) -> Self {
+        Self::from_rotation_translation(Unit::identity(), translation)
+    }
+
+    // Scale and shear are stripped by Mat4::decompose before the rotation is extracted.
+    pub fn from_mat4(mat: Mat4<T>) -> Self
+    where
+        T: Default,
+    {
+        let (translation, rotation, _) = mat.decompose();
+        Self::from_rotation_translation(rotation, translation)
+    }
+
+    pub fn to_mat4(&self) -> Mat4<T>
+    where
+        T: Default,
+    {
+        // Works on a normalized copy, so drift in either part does not skew the matrix.
+        let mut unit = *self;
+        unit.normalize();
+        Mat4::from_scale_rotation_translation(
+            Vec3::new(T::one(), T::one(), T::one()),
+            Unit::new_unchecked(unit.rotation()),
+            unit.translation(),
+        )
+    }
+
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.real
+    }
+
+    pub fn translation(&self) -> Vec3<T> {
+        (self.dual * self.real.conjugate()).vector * T::two()
+    }
+
+    // Conjugates both parts as quaternions. For a unit dual quaternion this is the inverse.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    // Negates the dual part.
+    pub fn dual_conjugate(&self) -> Self {
+        Self::new(self.real, self.dual * T::neg_one())
+    }
+
+    // Both conjugates combined. Transforms points written as 1 + epsilon * p.
+    pub fn combined_conjugate(&self) -> Self {
+        Self::new(self.real.conjugate(), self.dual.conjugate() * T::neg_one())
+    }
+
+    pub fn inverse(&self) -> Self {
+        let real = self.real.inverse();
+        Self::new(real, real * self.dual * real * T::neg_one())
+    }
+
+    // Scales to a unit real part and removes any drift that made the parts non orthogonal.
+    pub fn normalize(&mut self) {
+        let mag = self.real.magnitude();
+        if mag == T::zero() {
+            return;
+        }
+
+        let inv_mag = T::one() / mag;
+        self.real = self.real * inv_mag;
+        self.dual = self.dual * inv_mag;
+        self.dual -= self.real * self.real.dot(&self.dual);
+    }
+
+    pub fn transform_point(&self, point: Vec3<T>) -> Vec3<T> {
+        self.real * point + self.translation()
+    }
+
+    pub fn transform_vector(&self, vector: Vec3<T>) -> Vec3<T> {
+        self.real * vector
+    }
+
+    // Raises a unit dual quaternion to a power by scaling its screw motion: the angle about and
+    // the distance along the screw axis are both multiplied by `exponent`.
+    pub fn powf(&self, exponent: T) -> Self {
+        let two = T::two();
+        let sin_half = self.real.vector.magnitude();
+
+        if sin_half <= T::epsilon().sqrt() {
+            // The screw axis is ill defined this close to a pure translation, so scale the small
+            // rotation and the translation separately.
+            let mut rotation = self.real.powf(exponent);
+            rotation.normalize();
+            return Self::from_rotation_translation(
+                Unit::new_unchecked(rotation),
+                self.translation() * exponent,
+            );
+        }
+
+        let angle = two * sin_half.atan2(self.real.scalar);
+        let direction = self.real.vector * (T::one() / sin_half);
+        let pitch = T::zero() - two * self.dual.scalar / sin_half;
+        let moment = (self.dual.vector - direction * (pitch * T::one_half() * self.real.scalar))
+            * (T::one() / sin_half);
+
+        let angle = angle * exponent;
+        let pitch = pitch * exponent;
+        let (sin, cos) = (angle * T::one_half()).sin_cos();
+
+        Self::new(
+            Quaternion::new(cos, direction * sin),
+            Quaternion::new(
+                T::zero() - pitch * T::one_half() * sin,
+                moment * sin + direction * (pitch * T::one_half() * cos),
+            ),
+        )
+    }
+
+    // Screw linear interpolation. Follows the constant speed screw motion between the two
+    // transforms, taking the shorter rotation.
+    pub fn sclerp(&self, other: &DualQuaternion<T>, frac: T) -> Self {
+        let other = if self.real.dot(&other.real) < T::zero() {
+            *other * T::neg_one()
+        } else {
+            *other
+        };
+
+        *self * (self.conjugate() * other).powf(frac)
+    }
+
+    // Dual quaternion linear blending, as used for skinning. Each input is flipped into the
+    // hemisphere of the first before the weighted sum is normalized. None for empty input, a
+    // weight count that does not match, or weights that sum to zero.
+    pub fn blend(transforms: &[DualQuaternion<T>], weights: &[T]) -> Option<Self> {
+        let total = weights
+            .iter()
+            .fold(T::zero(), |total, weight| total + *weight);
+        if transforms.len() != weights.len() || total == T::zero() {
+            return None;
+        }
+
+        let first = transforms.first()?;
+        let mut sum = Self::new(Self::zero_quaternion(), Self::zero_quaternion());
+        for (dq, weight) in transforms.iter().zip(weights) {
+            let weight = if first.real.dot(&dq.real) < T::zero() {
+                T::zero() - *weight
+            } else {
+                *weight
+            };
+            sum = sum + *dq * weight;
+        }
+
+        if sum.real.magnitude() <= T::epsilon() {
+            return None;
+        }
+        sum.normalize();
+        Some(sum)
+    }
+}
+
+impl<T> Add for DualQuaternion<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+{
+    type Output = DualQuaternion<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            real: self.real + rhs.real,
+            dual: self.dual + rhs.dual,
+        }
+    }
+}
+
+impl<T> Mul for DualQuaternion<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Div<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = DualQuaternion<T>;
+
+    // Applies rhs first, then self.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            real: self.real * rhs.real,
+            dual: self.real * rhs.dual + self.dual * rhs.real,
+        }
+    }
+}
+
+impl<T> Mul<T> for DualQuaternion<T>
+where
+    T: Float,
+    T: Mul<Output = T>,
+{
+    type Output = DualQuaternion<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            real: self.real * rhs,
+            dual: self.dual * rhs,
+        }
+    }
+}
+
+impl<T> Mul<Vec3<T>> for DualQuaternion<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: PartialOrd,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: Vec3<T>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
@@ -1,16 +1,25 @@
+pub mod angle;
+pub mod approx;
 pub mod base;
+pub mod dual_quaternion;
 pub mod matrix;
 pub mod quaternion;
+pub mod swizzle;
+pub mod unit;
 pub mod vector;
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Re-Exports
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub use base::Float;
-pub use matrix::{Mat2, Mat3, Mat4, Matrix, MatrixOps};
-pub use quaternion::{rotate_on_axis, Quaternion};
-pub use vector::{Vec2, Vec3, Vec4, Vector, VectorOps};
+pub use angle::{Deg, Rad};
+pub use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+pub use base::{Float, Number, Signed};
+pub use dual_quaternion::DualQuaternion;
+pub use matrix::{ClipDepth, FloatMatrixOps, Mat2, Mat3, Mat4, Matrix, MatrixOps, SignedMatrixOps};
+pub use quaternion::{rotate_on_axis, EulerRot, Quaternion};
+pub use unit::{Normed, Unit};
+pub use vector::{CrossOps, FloatVectorOps, Vec2, Vec3, Vec4, Vector, VectorOps};
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Tests
@@ -18,9 +27,526 @@ pub use vector::{Vec2, Vec3, Vec4, Vector, VectorOps};
 
 #[rustfmt::skip]
 #[cfg(test)]
+#[allow(clippy::approx_constant, clippy::excessive_precision)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_float() {
+        use std::ops::{Add, Div, Mul, Sub};
+        fn check<T>(tolerance: T)
+        where
+            T: Float + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + PartialOrd + std::fmt::Debug,
+        {
+            let close = |a: T, b: T| (a - b).abs() <= tolerance;
+            let two = T::two();
+            let half = T::one_half();
+
+            //test trigonometry
+            assert!(close(T::frac_pi_4().tan(), T::one()));
+            assert!(close(half.asin(), T::pi() / (two * two + two)));
+            assert!(close(T::one().atan(), T::frac_pi_4()));
+            assert!(close(T::one().atan2(T::neg_one()), T::pi() - T::frac_pi_4()));
+            assert!(close(T::zero().sinh(), T::zero()) && close(T::zero().cosh(), T::one()) && close(T::zero().tanh(), T::zero()));
+            assert!(close(T::tau(), two * T::pi()) && close(T::frac_pi_2(), T::pi() * half));
+
+            //test exponentials
+            assert!(close(T::one().exp().ln(), T::one()));
+            assert!(close(two.powf(two + T::one()), two * two * two) && close(two.powi(3), two * two * two));
+            assert!(close(two.exp2(), two * two) && close((two * two).log2(), two) && close((two * two).log(two), two));
+            assert!(close((two * two * two).cbrt(), two) && close((two * two).sqrt(), two));
+            assert!(close((two + T::one()).hypot(two * two), two * two + T::one()));
+            assert!(close(two.mul_add(two, T::one()), two * two + T::one()));
+
+            //test rounding
+            let x = two + half * half;
+            assert_eq!(x.floor(), two);
+            assert_eq!(x.ceil(), two + T::one());
+            assert_eq!(x.round(), two);
+            assert_eq!((T::zero() - x).trunc(), T::zero() - two);
+            assert_eq!(x.fract(), half * half);
+            assert_eq!(two.recip(), half);
+
+            //test sign and comparisons
+            assert_eq!((T::zero() - x).abs(), x);
+            assert_eq!((T::zero() - x).signum(), T::neg_one());
+            assert_eq!(two.copysign(T::neg_one()), T::zero() - two);
+            assert!((T::zero() - x).is_sign_negative() && !x.is_sign_negative());
+            assert_eq!(two.min(half), half);
+            assert_eq!(two.max(half), two);
+            assert_eq!(x.clamp(T::zero(), two), two);
+
+            //test special values
+            assert!(T::nan().is_nan() && !T::nan().is_finite());
+            assert!(T::infinity().is_infinite() && T::neg_infinity().is_infinite());
+            assert!(T::max_value().is_finite() && T::min_value() < T::zero());
+            assert!(T::one() + T::epsilon() > T::one());
+        }
+        check::<f32>(1e-6);
+        check::<f64>(1e-12);
+    }
+
+    #[test]
+    fn test_approx() {
+        //test aprox_eq and approx_eq are symmetric
+        assert!(!1.0f64.aprox_eq(5.0, 0.1) && !5.0f64.aprox_eq(1.0, 0.1));
+        assert!(1.0f64.aprox_eq(1.05, 0.1) && 1.05f64.aprox_eq(1.0, 0.1));
+        assert!(!Vec2::new(0.0, 0.0).approx_eq(Vec2::new(5.0, 5.0), 0.1));
+        assert!(!Vec3::new(0.0, 0.0, 0.0).approx_eq(Vec3::new(0.0, 0.0, 5.0), 0.1));
+        assert!(!Vector::approx_eq(Vec4::new(1.0, 2.0, 3.0, 4.0), Vec4::new(1.0, 2.0, 3.0, 0.0), 0.1));
+        assert!(Vec4::new(1.0, 2.0, 3.0, 4.0).approx_eq(Vec4::new(1.05, 1.95, 3.0, 4.0), 0.1));
+
+        //test abs_diff_eq
+        assert!(abs_diff_eq!(1.0f64, 1.0 + f64::EPSILON));
+        assert!(!abs_diff_eq!(1.0f64, 1.0 + 4.0 * f64::EPSILON));
+        assert!(abs_diff_eq!(1.0f32, 1.05, epsilon = 0.1) && abs_diff_eq!(1.05f32, 1.0, epsilon = 0.1));
+        assert!(!abs_diff_eq!(1.0f32, 5.0, epsilon = 0.1) && !abs_diff_eq!(5.0f32, 1.0, epsilon = 0.1));
+        assert!(abs_diff_eq!(f64::INFINITY, f64::INFINITY) && !abs_diff_eq!(f64::NAN, f64::NAN));
+        assert!(1.0f64.abs_diff_ne(&2.0, 0.5));
+
+        //test relative_eq
+        assert!(relative_eq!(1.0e10f64, 1.0e10 + 1.0, max_relative = 1e-9));
+        assert!(!abs_diff_eq!(1.0e10f64, 1.0e10 + 1.0, epsilon = 1e-9));
+        assert!(!relative_eq!(1.0e10f64, 1.01e10, max_relative = 1e-9));
+        assert!(relative_eq!(0.0f64, 1e-12, epsilon = 1e-9, max_relative = 1e-9));
+        assert!(!relative_eq!(0.0f64, 1e-12, max_relative = 1e-3));
+        assert!(relative_eq!(f32::INFINITY, f32::INFINITY) && !relative_eq!(f32::INFINITY, f32::MAX));
+        assert!(!relative_eq!(f64::NAN, f64::NAN));
+
+        //test ulps_eq
+        let next = f64::from_bits(1.0f64.to_bits() + 3);
+        assert!(ulps_eq!(1.0, next) && ulps_eq!(next, 1.0));
+        assert!(!ulps_eq!(1.0, next, max_ulps = 2));
+        assert!(ulps_eq!(1.0f32, f32::from_bits(1.0f32.to_bits() - 4)));
+        assert!(!ulps_eq!(1.0f32, f32::from_bits(1.0f32.to_bits() - 5)));
+        assert!(ulps_eq!(-0.0f64, 0.0) && !ulps_eq!(-1e-300f64, 1e-300, epsilon = 0.0));
+        assert!(ulps_eq!(-1e-300f64, 1e-300, epsilon = 1e-299));
+        assert!(!ulps_eq!(f64::NAN, f64::NAN));
+
+        //test compound types
+        let vec = Vec3::new(1.0, -2.0, 3.0);
+        assert_abs_diff_eq!(vec, vec + Vec3::new(0.0, 1e-7, 0.0), epsilon = 1e-6);
+        assert_abs_diff_ne!(vec, vec + Vec3::new(0.0, 1e-5, 0.0), epsilon = 1e-6);
+        assert_relative_eq!(Vec2::new(1e8, 1.0), Vec2::new(1e8 + 1e-2, 1.0), max_relative = 1e-9);
+        assert_relative_ne!(Vec4::new(1.0, 2.0, 3.0, 4.0), Vec4::new(1.0, 2.0, 3.0, 4.1));
+        assert_ulps_eq!(Vec3::new(0.1 + 0.2, 1.0, 0.0), Vec3::new(0.3, 1.0, 0.0));
+        assert_ulps_ne!(Vec3::new(0.1 + 0.2, 1.0, 0.0), Vec3::new(0.3, 1.0, 0.0), epsilon = 0.0, max_ulps = 0);
+
+        let rotation = Unit::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 2.0, 3.0)).unwrap(), Rad(0.7));
+        let q = *rotation;
+        assert_ulps_eq!(q * q.inverse(), Quaternion::identity());
+        assert_relative_eq!(q.powf(2.0), q * q, epsilon = 1e-12);
+        assert_abs_diff_ne!(q, q * -1.0);
+
+        let mat = Mat4::from_scale_rotation_translation(Vec3::new(1.0, 2.0, 3.0), rotation, vec);
+        assert_relative_eq!(Matrix::mult_mat(mat, mat.inverse()), Mat4::identity(), epsilon = 1e-12);
+        assert_abs_diff_eq!(Mat3::from_quaternion(rotation).transpose(), Mat3::from_quaternion(rotation.inverse()), epsilon = 1e-12);
+        assert_abs_diff_ne!(Mat2::new(1.0, 0.0, 0.0, 1.0), Mat2::new(1.0, 0.0, 0.0, -1.0));
+
+        let dq = DualQuaternion::from_rotation_translation(rotation, vec);
+        assert_relative_eq!(dq * dq.inverse(), DualQuaternion::identity(), epsilon = 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn test_approx_assert_fails() {
+        assert_relative_eq!(Vec2::new(1.0, 2.0), Vec2::new(1.0, 2.001), max_relative = 1e-6);
+    }
+
+    #[test]
+    fn test_integer_types() {
+        //test integer vectors
+        let pixel = Vec2::new(3i32, -4);
+        assert_eq!(pixel + Vec2::new(1, 1), Vec2::new(4, -3));
+        assert_eq!(-pixel, Vec2::new(-3, 4));
+        assert_eq!(pixel * 2, Vec2::new(6, -8));
+        assert_eq!(pixel.dot(Vec2::new(2, 1)), 2);
+        assert_eq!(pixel.sq_magnitude(), 25);
+        assert_eq!(Vector::distance(pixel, Vec2::zeroed()), Vec2::new(-3, 4));
+        assert_eq!(Vec2::<i32>::unit_y(), Vec2::new(0, 1));
+        assert_eq!(format!("{}", pixel), "vec2(3, -4)");
+
+        let voxel = Vec3::new(1u32, 2, 3);
+        assert_eq!(voxel + Vec3::unit_z(), Vec3::new(1, 2, 4));
+        assert_eq!(Vector::scale(voxel, 3), Vec3::new(3, 6, 9));
+        assert_eq!(Vec3::new(1i64, 0, 0).cross(Vec3::new(0, 1, 0)), Vec3::new(0, 0, 1));
+        let mut acc = Vec4::from([1u8, 2, 3, 4]);
+        acc += Vec4::new(1, 1, 1, 1);
+        acc -= Vec4::new(0, 1, 0, 1);
+        acc *= 2;
+        assert_eq!(acc, Vec4::new(4, 4, 8, 8));
+        assert_eq!(Vec3::from(acc), Vec3::new(4, 4, 8));
+
+        //test integer matrices
+        let mat = Mat2::new(2i32, 1, -1, 3);
+        assert_eq!(mat * Vec2::new(1, 2), Vec2::new(4, 5));
+        assert_eq!(mat * Mat2::identity(), mat);
+        assert_eq!(mat.transpose(), Mat2::new(2, -1, 1, 3));
+        assert_eq!(Matrix::trace(mat), 5);
+        assert_eq!(mat.determinant(), 7);
+        assert_eq!(mat.adjugate() * mat, Mat2::identity() * 7);
+
+        let mat = Mat3::from([[2i64, 0, 1], [1, 3, 2], [1, 1, 2]]);
+        assert_eq!(Matrix::determinant(mat), 6);
+        assert_eq!(mat * mat.adjugate(), Mat3::identity() * 6);
+        assert_eq!(Mat4::<u16>::identity().get_column(matrix::Column::W), Vec4::new(0, 0, 0, 1));
+        assert_eq!(Mat4::<u16>::identity().mult_vec(Vec4::new(1, 2, 3, 4)), Vec4::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    fn test_angle() {
+        //test conversions
+        assert_relative_eq!(Rad::from(Deg(180.0f64)), Rad::half_turn());
+        assert_relative_eq!(Deg::from(Rad(std::f64::consts::FRAC_PI_4)), Deg(45.0));
+        assert_eq!(Deg::<f32>::full_turn(), Deg(360.0));
+        assert_eq!(Deg::<f64>::quarter_turn(), Deg(90.0));
+        let rad: Rad<f64> = Deg(90.0).into();
+        assert_relative_eq!(rad, Rad::quarter_turn());
+
+        //test arithmetic
+        assert_eq!(Deg(30.0) + Deg(60.0), Deg(90.0));
+        assert_eq!(Deg(30.0) - Deg(60.0), -Deg(30.0));
+        assert_eq!(Rad(1.5) * 2.0, Rad(3.0));
+        assert_eq!(Rad(1.5) / 3.0, Rad(0.5));
+        assert_eq!(Deg(90.0) / Deg(30.0), 3.0);
+        assert_eq!(Deg(370.0) % Deg(360.0), Deg(10.0));
+        let mut angle = Deg(10.0);
+        angle += Deg(20.0);
+        angle -= Deg(5.0);
+        angle *= 4.0;
+        angle /= 2.0;
+        assert_eq!(angle, Deg(50.0));
+        assert!(Rad(0.1) < Rad(0.2));
+        assert_eq!(format!("{}", Deg(45.0)), "45°");
+        assert_eq!(format!("{}", Rad(1.5)), "1.5 rad");
+
+        //test normalize
+        assert_eq!(Deg(370.0).normalize(), Deg(10.0));
+        assert_eq!(Deg(-10.0).normalize(), Deg(350.0));
+        assert_eq!(Deg(720.0).normalize(), Deg(0.0));
+        assert_eq!(Deg(-1e-14).normalize(), Deg(0.0));
+        assert_eq!(Deg(190.0).normalize_signed(), Deg(-170.0));
+        assert_eq!(Deg(-540.0).normalize_signed(), Deg(-180.0));
+        assert_eq!(Deg(45.0).normalize_signed(), Deg(45.0));
+        assert_relative_eq!(Rad(-std::f64::consts::FRAC_PI_2).normalize(), Rad(3.0 * std::f64::consts::FRAC_PI_2));
+        assert_relative_eq!(Rad(5.0 * std::f64::consts::PI).normalize_signed(), -Rad::half_turn());
+        assert!(Rad(-1e-18f64).normalize() < Rad::full_turn());
+
+        //test trig
+        assert_relative_eq!(Deg(30.0f64).sin(), 0.5);
+        assert_relative_eq!(Deg(60.0f64).cos(), 0.5, epsilon = 1e-15);
+        assert_relative_eq!(Deg(45.0f64).tan(), 1.0, epsilon = 1e-15);
+        let (sin, cos) = Rad::quarter_turn().sin_cos();
+        assert_abs_diff_eq!(sin, 1.0f64);
+        assert_abs_diff_eq!(cos, 0.0);
+        assert_relative_eq!(Deg::asin(0.5f64), Deg(30.0), epsilon = 1e-12);
+        assert_relative_eq!(Deg::acos(0.5f64), Deg(60.0), epsilon = 1e-12);
+        assert_relative_eq!(Rad::atan(1.0f64), Rad(std::f64::consts::FRAC_PI_4));
+        assert_relative_eq!(Deg::atan2(-1.0f64, -1.0), Deg(-135.0));
+
+        //test rotation constructors accept either unit
+        let by_deg = Quaternion::from_axis_angle(Vec3::y_axis(), Deg(90.0));
+        let by_rad = Quaternion::from_axis_angle(Vec3::y_axis(), Rad::quarter_turn());
+        assert_relative_eq!(by_deg, by_rad);
+        assert_relative_eq!(Mat3::from_rotation_z(Deg(90.0)), Mat3::from_rotation_z(Rad::quarter_turn()));
+        assert_relative_eq!(Mat4::from_euler(EulerRot::XYZ, Deg(90.0), Rad(0.0), Deg(45.0)), Mat4::from_euler(EulerRot::XYZ, Rad::quarter_turn(), Rad(0.0), Rad(std::f64::consts::FRAC_PI_4)));
+        assert_abs_diff_eq!(rotate_on_axis(Vec3::unit_x(), Deg(90.0), Vec3::z_axis()), Vec3::unit_y(), epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_unit() {
+        //test construction
+        let unit = Unit::new_normalize(Vec3::new(0.0, 3.0, 4.0)).unwrap();
+        assert_relative_eq!(*unit, Vec3::new(0.0, 0.6, 0.8));
+        assert_relative_eq!(unit.magnitude(), 1.0);
+        assert_eq!(Unit::new_normalize(Vec3::new(0.0, 0.0, 0.0)), None);
+        assert_eq!(Unit::new_normalize(Vec2::new(f64::NAN, 1.0)), None);
+        assert_eq!(Unit::new_normalize(Vec4::new(f32::INFINITY, 0.0, 0.0, 0.0)), None);
+        assert_eq!(Unit::try_new(Vec2::new(0.1, 0.0), 0.5), None);
+        assert_relative_eq!(Unit::try_new(Vec2::new(0.6, 0.0), 0.5).unwrap().into_inner(), Vec2::unit_x());
+        assert_relative_eq!(*Unit::new_normalize(Vec4::new(2.0, 0.0, 0.0, 2.0)).unwrap(), Vec4::new(0.5f64.sqrt(), 0.0, 0.0, 0.5f64.sqrt()));
+        assert_eq!(*Vec3::<f32>::z_axis(), Vec3::unit_z());
+        assert_eq!(*Vec2::<f64>::y_axis(), Vec2::unit_y());
+        assert_eq!(*Vec4::<f64>::w_axis(), Vec4::unit_w());
+        assert_eq!(*-Vec3::<f64>::x_axis(), Vec3::new(-1.0, 0.0, 0.0));
+
+        //test renormalize
+        let mut drifted = Unit::new_unchecked(Vec3::new(0.0, 0.0, 1.1));
+        drifted.renormalize();
+        assert_relative_eq!(drifted, Vec3::z_axis());
+
+        //test unit quaternions
+        let q = Unit::new_normalize(Quaternion::new(1.0, Vec3::new(1.0, 1.0, 1.0))).unwrap();
+        assert_relative_eq!(q.magnitude(), 1.0);
+        assert_eq!(Unit::new_normalize(Quaternion::new(0.0, Vec3::zeroed())), None);
+        assert_relative_eq!(q * q.inverse(), Unit::identity(), epsilon = 1e-15);
+        let r = Unit::<Quaternion<f64>>::from_axis_angle(Vec3::y_axis(), Deg(90.0));
+        assert_relative_eq!((q * r).into_inner(), *q * *r);
+        assert_relative_eq!(r * Vec3::unit_z(), Vec3::unit_x(), epsilon = 1e-15);
+        assert_relative_eq!(q * Vec3::unit_x(), Vec3::unit_y(), epsilon = 1e-15);
+
+        //test rotation constructors take unit axes
+        let axis = Unit::new_normalize(Vec3::new(0.0, 0.0, 5.0)).unwrap();
+        assert_relative_eq!(Mat3::from_axis_angle(axis, Deg(90.0)), Mat3::from_rotation_z(Deg(90.0)));
+        assert_relative_eq!(Mat4::from_axis_angle(axis, Deg(90.0)), Mat4::from_rotation_z(Deg(90.0)));
+        assert_relative_eq!(*r, Quaternion::from_axis_angle(Vec3::y_axis(), Deg(90.0)));
+
+        //test rotation constructors take unit quaternions
+        let q = Unit::<Quaternion<f64>>::from_euler(EulerRot::XYZ, Rad(0.3), Rad(-0.2), Rad(1.1));
+        assert_relative_eq!(Mat3::from_quaternion(q), Mat3::from_euler(EulerRot::XYZ, Rad(0.3), Rad(-0.2), Rad(1.1)), epsilon = 1e-12);
+        assert_relative_eq!(Mat3::from_quaternion(Unit::from_rotation_matrix(Mat3::from_quaternion(q))), Mat3::from_quaternion(q), epsilon = 1e-12);
+        assert_relative_eq!(Mat4::from_scale_rotation_translation(Vec3::new(1.0, 1.0, 1.0), q, Vec3::zeroed()), Mat4::from_quaternion(q));
+        assert_relative_eq!(DualQuaternion::from_rotation_translation(q, Vec3::zeroed()).rotation(), *q);
+        let arc = Unit::from_rotation_arc(Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 3.0, 0.0));
+        assert_relative_eq!(Mat3::from_quaternion(arc) * Vec3::unit_x(), Vec3::unit_y(), epsilon = 1e-12);
+        let look = Unit::look_rotation(Vec3::new(1.0, 0.0, 1.0), Vec3::unit_y());
+        assert_relative_eq!(Mat3::from_quaternion(look) * Vec3::unit_z(), Vec3::new(1.0, 0.0, 1.0).normalize(), epsilon = 1e-12);
+
+        //test deprecated unit_norm leaves quaternions without an axis alone
+        #[allow(deprecated)]
+        {
+            let mut q = Quaternion::new(90.0, Vec3::zeroed());
+            q.unit_norm();
+            assert_eq!(q, Quaternion::new(90.0, Vec3::zeroed()));
+            let mut q = Quaternion::new(90.0, Vec3::new(0.0, 0.0, 2.0));
+            q.unit_norm();
+            assert_relative_eq!(q, Quaternion::from_axis_angle(Vec3::z_axis(), Deg(90.0)));
+        }
+    }
+
+    #[test]
+    fn test_degenerate_vectors() {
+        //test try_normalize
+        assert_eq!(Vec2::new(0.0f64, 0.0).try_normalize(), None);
+        assert_eq!(Vec3::new(0.0f32, 0.0, 0.0).try_normalize(), None);
+        assert_eq!(Vec4::new(f64::NAN, 0.0, 0.0, 0.0).try_normalize(), None);
+        assert_eq!(Vec3::new(f64::INFINITY, 0.0, 0.0).try_normalize(), None);
+        assert_relative_eq!(Vector::try_normalize(Vec2::new(3.0, 4.0)).unwrap(), Vec2::new(0.6, 0.8));
+        assert_relative_eq!(Vec3::new(1e-150, 0.0, 0.0).try_normalize().unwrap(), Vec3::unit_x());
+        assert!(Vec3::new(0.0, 0.0, 0.0).normalize().x.is_nan());
+
+        //test normalize_or and normalize_or_zero
+        assert_eq!(Vec3::new(0.0, 0.0, 0.0).normalize_or(Vec3::unit_y()), Vec3::unit_y());
+        assert_eq!(Vec3::new(0.0, 0.0, 2.0).normalize_or(Vec3::unit_y()), Vec3::unit_z());
+        assert_eq!(Vec4::new(0.0, 0.0, 0.0, 0.0).normalize_or_zero(), Vec4::zeroed());
+        assert_eq!(Vector::normalize_or_zero(Vec2::new(f32::NAN, 1.0)), Vec2::zeroed());
+        assert_eq!(Vector::normalize_or(Vec2::new(0.0, -5.0), Vec2::unit_x()), Vec2::new(0.0, -1.0));
+
+        //test is_normalized
+        assert!(Vec3::new(0.0, 0.6, 0.8).is_normalized());
+        assert!(Vec2::new(1.0f32, 0.0).is_normalized());
+        assert!(!Vec3::new(0.0, 0.0, 0.0).is_normalized());
+        assert!(!Vec4::new(1.0, 1.0, 0.0, 0.0).is_normalized());
+        assert!(!Vector::is_normalized(Vec2::new(f64::NAN, 0.0)));
+        assert!(Vec3::new(1.0, 2.0, 3.0).normalize().is_normalized());
+
+        //test angle
+        assert_eq!(Vec3::new(0.0, 0.0, 0.0).angle(Vec3::unit_x()), Rad(0.0));
+        assert_eq!(Vec2::unit_x().angle(Vec2::new(0.0, 0.0)), Rad(0.0));
+        let v = Vec3::new(0.1f64, 0.2, 0.3);
+        assert_abs_diff_eq!(v.angle(v * 3.0), Rad(0.0), epsilon = 1e-7);
+        assert_abs_diff_eq!(v.angle(v * -3.0), Rad(std::f64::consts::PI), epsilon = 1e-7);
+        let v = Vec4::new(1e-3f32, 7.0, -3.0, 0.5);
+        assert!(!v.angle(v).0.is_nan() && !v.angle(v * -1.0).0.is_nan());
+        assert_relative_eq!(Vector::angle(Vec2::new(2.0, 0.0), Vec2::new(0.0, 0.5)), Rad(std::f64::consts::FRAC_PI_2));
+        assert_relative_eq!(Vec3::new(1.0, 1.0, 0.0).angle(Vec3::new(5.0, 0.0, 0.0)), Rad(std::f64::consts::FRAC_PI_4));
+        assert_relative_eq!(Vec3::new(1e-100, 0.0, 0.0).angle(Vec3::new(0.0, 1e-100, 0.0)), Rad(std::f64::consts::FRAC_PI_2));
+        assert_relative_eq!(Vec2::new(1e-100, 1e-100).angle(Vec2::new(1e-100, 0.0)), Rad(std::f64::consts::FRAC_PI_4));
+        assert_relative_eq!(Vec4::new(0.0, 0.0, 1e-150, 0.0).angle(Vec4::new(0.0, 0.0, 0.0, 2e-150)), Rad(std::f64::consts::FRAC_PI_2));
+    }
+
+    #[test]
+    fn test_cross_products() {
+        //test Vec2 perp and perp_dot
+        let a = Vec2::new(3.0, 1.0);
+        let b = Vec2::new(-1.0, 2.0);
+        assert_eq!(a.perp(), Vec2::new(-1.0, 3.0));
+        assert_eq!(a.perp().dot(a), 0.0);
+        assert_eq!(a.perp_dot(b), 7.0);
+        assert_eq!(b.perp_dot(a), -7.0);
+        assert_eq!(a.perp_dot(a * 2.0), 0.0);
+        assert_eq!(a.perp_dot(b), a.perp().dot(b));
+        assert_eq!(Vec2::<f64>::unit_x().perp_dot(Vec2::unit_y()), 1.0);
+        let lifted = Vec3::from(a).cross(Vec3::from(b));
+        assert_eq!(lifted, Vec3::new(0.0, 0.0, a.perp_dot(b)));
+        assert_eq!(Vec2::new(2i32, 5).perp(), Vec2::new(-5, 2));
+        assert_eq!(Vec2::new(2i32, 5).perp_dot(Vec2::new(1, 1)), -3);
+
+        //test Vec3 cross
+        assert_eq!(Vector::cross(Vec3::<f64>::unit_x(), Vec3::unit_y()), Vec3::unit_z());
+        assert_eq!(Vec3::unit_y().cross(Vec3::unit_x()), Vec3::new(0.0, 0.0, -1.0));
+        assert_eq!(Vec3::<i32>::unit_y().cross(Vec3::unit_x()), Vec3::new(0, 0, -1));
+        assert_eq!(Vec3::new(1i8, 2, 3).cross(Vec3::new(-2, 0, 4)), Vec3::new(8, -10, 4));
+        assert_eq!(Vec4::new(0i64, 1, 0, 9).cross(Vec4::unit_x()), Vec4::new(0, 0, -1, 0));
+
+        //test Vec4 cross over xyz
+        let a = Vec4::new(1.0, 2.0, 3.0, 1.0);
+        let b = Vec4::new(-2.0, 0.5, 4.0, 7.0);
+        let res = a.cross(b);
+        assert_eq!(Vec3::from(res), Vec3::from(a).cross(Vec3::from(b)));
+        assert_eq!(res.w, 0.0);
+        assert_eq!(Vector::cross(Vec4::<f64>::unit_y(), Vec4::unit_z()), Vec4::unit_x());
+        assert_eq!(Vec4::<f64>::unit_w().cross(Vec4::unit_x()), Vec4::zeroed());
+    }
+
+    #[test]
+    fn test_componentwise() {
+        let a = Vec3::new(2.0, -3.0, 4.5);
+        let b = Vec3::new(4.0, 2.0, -1.5);
+
+        //test operators
+        assert_eq!(a * b, Vec3::new(8.0, -6.0, -6.75));
+        assert_eq!(a / b, Vec3::new(0.5, -1.5, -3.0));
+        assert_eq!(a / 2.0, Vec3::new(1.0, -1.5, 2.25));
+        assert_eq!(a % Vec3::new(1.5, 2.0, 2.0), Vec3::new(0.5, -1.0, 0.5));
+        assert_eq!(Vec2::new(7, -7) % 3, Vec2::new(1, -1));
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(3u32 * Vec4::new(1, 2, 3, 4), Vec4::new(3, 6, 9, 12));
+        assert_eq!(0.5f32 * Vec2::new(4.0, 2.0), Vec2::new(2.0, 1.0));
+        let mut c = a;
+        c *= b;
+        c /= b;
+        assert_eq!(c, a);
+        c /= 2.0;
+        assert_eq!(c, a / 2.0);
+        let mut d = Vec4::new(5, 6, 7, 8);
+        d %= 4;
+        assert_eq!(d, Vec4::new(1, 2, 3, 0));
+        d %= Vec4::new(2, 2, 2, 5);
+        assert_eq!(d, Vec4::new(1, 0, 1, 0));
+
+        //test min, max and clamp
+        assert_eq!(a.min(b), Vec3::new(2.0, -3.0, -1.5));
+        assert_eq!(a.max(b), Vec3::new(4.0, 2.0, 4.5));
+        assert_eq!(a.clamp(Vec3::new(0.0, 0.0, 0.0), Vec3::new(3.0, 3.0, 3.0)), Vec3::new(2.0, 0.0, 3.0));
+        assert_eq!(Vec2::new(-5i32, 9).clamp(Vec2::new(-1, -1), Vec2::new(1, 1)), Vec2::new(-1, 1));
+
+        //test reductions
+        assert_eq!(a.min_element(), -3.0);
+        assert_eq!(a.max_element(), 4.5);
+        assert_eq!(a.sum(), 3.5);
+        assert_eq!(a.product(), -27.0);
+        assert_eq!(Vec4::new(3u8, 1, 4, 1).min_element(), 1);
+        assert_eq!(Vec4::new(3u8, 1, 4, 1).max_element(), 4);
+        assert_eq!(Vec2::new(3, 4).sum(), 7);
+        assert_eq!(Vec2::new(3, 4).product(), 12);
+
+        //test sign functions
+        assert_eq!(a.abs(), Vec3::new(2.0, 3.0, 4.5));
+        assert_eq!(a.signum(), Vec3::new(1.0, -1.0, 1.0));
+        assert_eq!(Vec2::new(-4i64, 0).abs(), Vec2::new(4, 0));
+        assert_eq!(Vec2::new(-4i64, 0).signum(), Vec2::new(-1, 0));
+
+        //test rounding
+        let v = Vec4::new(1.25, -1.25, 2.5, -0.75);
+        assert_eq!(v.floor(), Vec4::new(1.0, -2.0, 2.0, -1.0));
+        assert_eq!(v.ceil(), Vec4::new(2.0, -1.0, 3.0, -0.0));
+        assert_eq!(v.round(), Vec4::new(1.0, -1.0, 3.0, -1.0));
+        assert_eq!(v.fract(), Vec4::new(0.25, -0.25, 0.5, -0.75));
+        assert_eq!(v.recip(), Vec4::new(0.8, -0.8, 0.4, -1.0 / 0.75));
+        assert_eq!(Vec2::new(0.0f32, 2.0).recip(), Vec2::new(f32::INFINITY, 0.5));
+    }
+
+    #[test]
+    fn test_swizzles() {
+        let v2 = Vec2::new(1, 2);
+        let v3 = Vec3::new(1, 2, 3);
+        let v4 = Vec4::new(1, 2, 3, 4);
+
+        //test getters
+        assert_eq!(v2.yx(), Vec2::new(2, 1));
+        assert_eq!(v2.xxy(), Vec3::new(1, 1, 2));
+        assert_eq!(v2.yyxx(), Vec4::new(2, 2, 1, 1));
+        assert_eq!(v3.zyx(), Vec3::new(3, 2, 1));
+        assert_eq!(v3.zx(), Vec2::new(3, 1));
+        assert_eq!(v3.xxyy(), Vec4::new(1, 1, 2, 2));
+        assert_eq!(v4.wzyx(), Vec4::new(4, 3, 2, 1));
+        assert_eq!(v4.xyz(), Vec3::new(1, 2, 3));
+        assert_eq!(v4.ww(), Vec2::new(4, 4));
+
+        //test setters
+        let mut s = v3;
+        s.set_zx(Vec2::new(7, 8));
+        assert_eq!(s, Vec3::new(8, 2, 7));
+        let mut s = v4;
+        s.set_wzy(Vec3::new(7, 8, 9));
+        assert_eq!(s, Vec4::new(1, 9, 8, 7));
+        s.set_yxwz(Vec4::new(5, 6, 7, 8));
+        assert_eq!(s, Vec4::new(6, 5, 8, 7));
+        let mut s = v2;
+        s.set_yx(v2);
+        assert_eq!(s, Vec2::new(2, 1));
+
+        //test extensions
+        assert_eq!(v2.extend(5), Vec3::new(1, 2, 5));
+        assert_eq!(v2.xy0(), Vec3::new(1, 2, 0));
+        assert_eq!(v2.xy1(), Vec3::new(1, 2, 1));
+        assert_eq!(v3.xyz0(), Vec4::new(1, 2, 3, 0));
+        assert_eq!(v3.xyz1(), Vec4::new(1, 2, 3, 1));
+        assert_eq!(v3.truncate(), v2);
+        assert_eq!(v4.truncate(), v3);
+        assert_eq!(v3.extend(4), v4);
+    }
+
+    #[test]
+    fn test_geometric_ops() {
+        let normal = Vec3::new(0.0, 1.0, 0.0);
+
+        //test reflect
+        let incident = Vec3::new(1.0, -1.0, 0.0);
+        assert_eq!(incident.reflect(normal), Vec3::new(1.0, 1.0, 0.0));
+        assert_eq!(Vector::reflect(Vec2::new(1.0, -2.0), Vec2::new(0.0, 1.0)), Vec2::new(1.0, 2.0));
+        assert_eq!(Vec4::new(1.0, -1.0, 0.0, 0.0).reflect(Vec4::new(0.0, 1.0, 0.0, 0.0)), Vec4::new(1.0, 1.0, 0.0, 0.0));
+
+        //test refract
+        let incident = incident.normalize();
+        assert_relative_eq!(incident.refract(normal, 1.0).unwrap(), incident);
+        // Snell's law, sin(out) = eta * sin(in)
+        let refracted = incident.refract(normal, 1.0 / 1.5).unwrap();
+        assert_relative_eq!(refracted.magnitude(), 1.0);
+        assert_relative_eq!(refracted.x, (1.0 / 1.5) * incident.x);
+        assert!(refracted.y < 0.0);
+        // Leaving glass past the critical angle
+        assert_eq!(incident.refract(normal, 1.5), None);
+        assert_eq!(Vector::refract(-normal, normal, 1.5), Some(-normal));
+
+        //test project and reject
+        let v = Vec3::new(3.0, 4.0, 5.0);
+        let onto = Vec3::new(2.0, 0.0, 0.0);
+        assert_eq!(v.project_onto(onto), Vec3::new(3.0, 0.0, 0.0));
+        assert_eq!(v.reject_from(onto), Vec3::new(0.0, 4.0, 5.0));
+        assert_relative_eq!(Vector::project_onto(v, onto) + Vector::reject_from(v, onto), v);
+        assert_relative_eq!(v.reject_from(onto).dot(onto), 0.0);
+        assert!(v.project_onto(Vec3::zeroed()).x.is_nan());
+
+        //test faceforward
+        let incident = Vec3::new(0.0, -1.0, 0.0);
+        assert_eq!(normal.faceforward(incident, normal), normal);
+        assert_eq!((-normal).faceforward(incident, -normal), normal);
+        assert_eq!(Vector::faceforward(normal, -incident, normal), -normal);
+
+        //test orthonormal pair and basis
+        for v in [
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-3.0, 0.5, -0.01),
+        ] {
+            let n = v.normalize();
+            let (a, b) = n.any_orthonormal_pair();
+            assert_relative_eq!(a.magnitude(), 1.0);
+            assert_relative_eq!(b.magnitude(), 1.0);
+            assert_abs_diff_eq!(a.dot(b), 0.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(a.dot(n), 0.0, epsilon = 1e-12);
+            assert_abs_diff_eq!(b.dot(n), 0.0, epsilon = 1e-12);
+
+            let (x, y, z) = v.any_orthonormal_basis().unwrap();
+            assert_relative_eq!(z, n);
+            assert_relative_eq!(x.cross(y), z, epsilon = 1e-12);
+        }
+        assert_eq!(Vec3::<f64>::zeroed().any_orthonormal_basis(), None);
+    }
+
     #[test]
     fn vector_statics() {
         let vec2 = Vec2::new(1.0, 2.0);
@@ -106,15 +632,15 @@ mod tests {
         //test angle
         let vec1 = Vec2::new(1.0, 0.0);
         let vec2 = Vec2::new(0.0, 1.0);
-        let res = Vector::angle(vec1, vec2).to_degrees();
+        let res = Deg::from(Vector::angle(vec1, vec2)).0;
         assert!(f64::abs(res - 90.0) < 0.001);
         let vec1 = Vec3::new(1.0, 0.0, 0.0);
         let vec2 = Vec3::new(-3.0, 0.0, 0.0);
-        let res = Vector::angle(vec1, vec2).to_degrees();
+        let res = Deg::from(Vector::angle(vec1, vec2)).0;
         assert!(f64::abs(res - 180.0) < 0.001);
         let vec1 = Vec4::new(1.0, 0.0, 0.0, 0.0);
         let vec2 = Vec4::new(0.0, 4.0, 0.0, 0.0);
-        let res = Vector::angle(vec1, vec2).to_degrees();
+        let res = Deg::from(Vector::angle(vec1, vec2)).0;
         assert!(f64::abs(res - 90.0) < 0.001);
 
         //test lerp
@@ -238,7 +764,7 @@ mod tests {
         //test angle
         let vec1 = Vec2::new(1.0, 0.0);
         let vec2 = Vec2::new(0.0, 1.0);
-        let res = vec1.angle(vec2).to_degrees();
+        let res = Deg::from(vec1.angle(vec2)).0;
         assert!(f64::abs(res - 90.0) < 0.001);
 
         //test lerp
@@ -369,7 +895,7 @@ mod tests {
         //test angle
         let vec1 = Vec3::new(1.0, 0.0, 0.0);
         let vec2 = Vec3::new(-3.0, 0.0, 0.0);
-        let res = vec1.angle(vec2).to_degrees();
+        let res = Deg::from(vec1.angle(vec2)).0;
         assert!(f64::abs(res - 180.0) < 0.001);
 
         //test lerp
@@ -505,7 +1031,7 @@ mod tests {
         //test angle
         let vec1 = Vec4::new(1.0, 0.0, 0.0, 0.0);
         let vec2 = Vec4::new(0.0, 4.0, 0.0, 0.0);
-        let res = vec1.angle(vec2).to_degrees();
+        let res = Deg::from(vec1.angle(vec2)).0;
         assert!(f64::abs(res - 90.0) < 0.001);
 
         //test lerp
@@ -603,6 +1129,10 @@ mod tests {
         let column = mat.get_column(matrix::Column::Y);
         assert_eq!(column, Vec2::new(2.0, 4.0));
 
+        //test get_position
+        let mat = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(mat.get_position(), Vec2::zeroed());
+
         //test get_row
         let mat = Mat2::new(1.0, 2.0, 3.0, 4.0);
         let row = mat.get_row(matrix::Row::X);
@@ -633,6 +1163,50 @@ mod tests {
         let vec = Vec2::new(1.0, 2.0);
         let res = mat * vec;
         assert_eq!(res, Vec2::new(5.0, 11.0));
+
+        //test determinant
+        let mat = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(mat.determinant(), -2.0);
+
+        //test cofactor
+        let mat = Mat2::new(2.0, 1.0, 0.0, 4.0);
+        assert_eq!(mat.cofactor(), Mat2::new(4.0, 0.0, -1.0, 2.0));
+
+        //test adjugate
+        let mat = Mat2::new(2.0, 1.0, 0.0, 4.0);
+        assert_eq!(mat.adjugate(), Mat2::new(4.0, -1.0, 0.0, 2.0));
+
+        //test inverse
+        let mat = Mat2::new(2.0, 1.0, 0.0, 4.0);
+        assert_eq!(mat.inverse(), Mat2::new(0.5, -0.125, 0.0, 0.25));
+        assert_eq!(mat * mat.inverse(), Mat2::identity());
+
+        //test try_inverse
+        let mat = Mat2::new(2.0, 1.0, 0.0, 4.0);
+        assert_eq!(Matrix::try_inverse(mat), Some(Mat2::new(0.5, -0.125, 0.0, 0.25)));
+        let mat = Mat2::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq!(mat.try_inverse(), None);
+        let mat = Mat2::new(0.001f32, 0.0, 0.0, 0.001);
+        assert_relative_eq!(mat.try_inverse().unwrap(), Mat2::new(1000.0, 0.0, 0.0, 1000.0), max_relative = 1e-6);
+        assert_eq!(Mat2::new(1e-3f32, 2e-3, 2e-3, 4e-3).try_inverse(), None);
+        assert_eq!(Mat2::<f64>::zeroed().try_inverse(), None);
+
+        //test transpose
+        let mat = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(mat.transpose(), Mat2::new(1.0, 3.0, 2.0, 4.0));
+        let mut res = mat;
+        res.transpose_mut();
+        assert_eq!(res, Matrix::transpose(mat));
+
+        //test trace
+        let mat = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(mat.trace(), 5.0);
+
+        //test mult_vec_transposed
+        let mat = Mat2::new(1.0, 2.0, 3.0, 4.0);
+        let vec = Vec2::new(1.0, 2.0);
+        assert_eq!(mat.mult_vec_transposed(vec), mat.transpose() * vec);
+        assert_eq!(vec * mat, Vec2::new(7.0, 10.0));
     }
 
     #[test]
@@ -671,6 +1245,10 @@ mod tests {
         let row = mat.get_row(matrix::Row::Z);
         assert_eq!(row, Vec3::new(7.0, 8.0, 9.0));
 
+        //test get_position
+        let mat = Mat3::new(1.0, 0.0, 3.0, 0.0, 1.0, 6.0, 0.0, 0.0, 1.0);
+        assert_eq!(mat.get_position(), Vec3::new(3.0, 6.0, 1.0));
+
         //test add
         let mat = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
         let res = mat + mat;
@@ -696,6 +1274,49 @@ mod tests {
         let vec = Vec3::new(1.0, 2.0, 3.0);
         let res = mat * vec;
         assert_eq!(res, Vec3::new(14.0, 32.0, 50.0));
+
+        //test determinant
+        let mat = Mat3::new(2.0, 1.0, 3.0, 0.0, 4.0, 5.0, 0.0, 0.0, 1.0);
+        assert_eq!(mat.determinant(), 8.0);
+        let mat = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(Matrix::determinant(mat), 0.0);
+
+        //test cofactor
+        let mat = Mat3::new(2.0, 1.0, 3.0, 0.0, 4.0, 5.0, 0.0, 0.0, 1.0);
+        assert_eq!(mat.cofactor(), Mat3::new(4.0, 0.0, 0.0, -1.0, 2.0, 0.0, -7.0, -10.0, 8.0));
+
+        //test adjugate
+        let mat = Mat3::new(2.0, 1.0, 3.0, 0.0, 4.0, 5.0, 0.0, 0.0, 1.0);
+        assert_eq!(mat.adjugate(), Mat3::new(4.0, -1.0, -7.0, 0.0, 2.0, -10.0, 0.0, 0.0, 8.0));
+
+        //test inverse
+        let mat = Mat3::new(2.0, 1.0, 3.0, 0.0, 4.0, 5.0, 0.0, 0.0, 1.0);
+        assert_eq!(mat.inverse(), Mat3::new(0.5, -0.125, -0.875, 0.0, 0.25, -1.25, 0.0, 0.0, 1.0));
+        assert_eq!(mat * mat.inverse(), Mat3::identity());
+
+        //test try_inverse
+        let mat = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(mat.try_inverse(), None);
+        let mat = Mat3::from_scale(Vec3::new(0.01f32, 0.01, 0.01));
+        assert_relative_eq!(mat.try_inverse().unwrap(), Mat3::from_scale(Vec3::new(100.0, 100.0, 100.0)), max_relative = 1e-6);
+        assert_eq!((Mat3::new(1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0) * 1e-3).try_inverse(), None);
+
+        //test transpose
+        let mat = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(mat.transpose(), Mat3::new(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0));
+        let mut res = mat;
+        res.transpose_mut();
+        assert_eq!(res, Matrix::transpose(mat));
+
+        //test trace
+        let mat = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(Matrix::trace(mat), 15.0);
+
+        //test mult_vec_transposed
+        let mat = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let vec = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(mat.mult_vec_transposed(vec), mat.transpose() * vec);
+        assert_eq!(vec * mat, Vec3::new(30.0, 36.0, 42.0));
     }
 
     #[test]
@@ -729,6 +1350,8 @@ mod tests {
         let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
         let column = mat.get_column(matrix::Column::W);
         assert_eq!(column, Vec4::new(4.0, 8.0, 12.0, 16.0));
+        let column = mat.get_column(matrix::Column::Z);
+        assert_eq!(column, Vec4::new(3.0, 7.0, 11.0, 15.0));
 
         //test get_row
         let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
@@ -766,6 +1389,776 @@ mod tests {
         let res = mat * vec;
         assert_eq!(res, Vec4::new(30.0, 70.0, 110.0, 150.0));
 
+        //test determinant
+        let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 2.0, 6.0, 4.0, 8.0, 3.0, 1.0, 1.0, 2.0);
+        assert_eq!(mat.determinant(), 72.0);
+        let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        assert_eq!(Matrix::determinant(mat), 0.0);
+
+        //test cofactor
+        let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 2.0, 6.0, 4.0, 8.0, 3.0, 1.0, 1.0, 2.0);
+        assert_eq!(mat.cofactor(), Mat4::new(-12.0, -60.0, 12.0, 42.0, 4.0, 20.0, 20.0, -26.0, -4.0, 16.0, -20.0, 8.0, 24.0, -24.0, -24.0, 24.0));
+
+        //test adjugate
+        let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 2.0, 6.0, 4.0, 8.0, 3.0, 1.0, 1.0, 2.0);
+        assert_eq!(mat.adjugate(), Mat4::new(-12.0, 4.0, -4.0, 24.0, -60.0, 20.0, 16.0, -24.0, 12.0, 20.0, -20.0, -24.0, 42.0, -26.0, 8.0, 24.0));
+
+        //test inverse
+        let mat = Mat4::new(2.0, 1.0, 0.0, 3.0, 0.0, 4.0, 1.0, 2.0, 0.0, 0.0, 1.0, 5.0, 0.0, 0.0, 0.0, 2.0);
+        assert_eq!(mat.inverse(), Mat4::new(0.5, -0.125, 0.125, -0.9375, 0.0, 0.25, -0.25, 0.375, 0.0, 0.0, 1.0, -2.5, 0.0, 0.0, 0.0, 0.5));
+        assert_eq!(mat * mat.inverse(), Mat4::identity());
+
+        //test try_inverse
+        let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        assert_eq!(mat.try_inverse(), None);
+        let mat = Mat4::<f32>::identity() * 0.01;
+        assert!(mat.determinant() < f32::EPSILON);
+        assert_relative_eq!(Matrix::try_inverse(mat).unwrap(), Mat4::identity() * 100.0, max_relative = 1e-6);
+        let mat = Mat4::from_scale(Vec3::new(0.01f32, 0.01, 0.01)) * Mat4::from_rotation_x(Deg(30.0));
+        assert_relative_eq!(mat * mat.try_inverse().unwrap(), Mat4::identity(), epsilon = 1e-5);
+        assert_eq!(Mat4::<f32>::zeroed().try_inverse(), None);
+
+        //test inverse_affine
+        let mat = Mat4::new(2.0, 0.0, 0.0, 3.0, 0.0, 4.0, 0.0, 2.0, 0.0, 0.0, 1.0, 5.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(mat.inverse_affine(), Mat4::new(0.5, 0.0, 0.0, -1.5, 0.0, 0.25, 0.0, -0.5, 0.0, 0.0, 1.0, -5.0, 0.0, 0.0, 0.0, 1.0));
+        assert_eq!(mat.inverse_affine(), mat.inverse());
+
+        //test try_inverse_affine
+        assert_eq!(mat.try_inverse_affine(), Some(mat.inverse_affine()));
+        let small = Mat4::from_translation(Vec3::new(1.0f32, 2.0, 3.0)) * Mat4::from_scale(Vec3::new(0.01, 0.01, 0.01));
+        assert_relative_eq!(small * small.try_inverse_affine().unwrap(), Mat4::identity(), epsilon = 1e-4);
+        assert_eq!(Mat4::from_scale(Vec3::new(1.0, 0.0, 1.0)).try_inverse_affine(), None);
+        assert_eq!(Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0)).mult_mat(Mat4::from_scale(Vec3::new(2.0, 2.0, 1e-20))).try_inverse_affine(), None);
+
+        //test inverse_orthonormal
+        let mat = Mat4::new(0.0, -1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 3.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!(mat.inverse_orthonormal(), Mat4::new(0.0, 1.0, 0.0, -2.0, -1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, -3.0, 0.0, 0.0, 0.0, 1.0));
+        assert_eq!(mat * mat.inverse_orthonormal(), Mat4::identity());
+
+        //test transpose
+        let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        assert_eq!(mat.transpose(), Mat4::new(1.0, 5.0, 9.0, 13.0, 2.0, 6.0, 10.0, 14.0, 3.0, 7.0, 11.0, 15.0, 4.0, 8.0, 12.0, 16.0));
+        let mut res = mat;
+        Matrix::transpose_mut(&mut res);
+        assert_eq!(res, mat.transpose());
+
+        //test trace
+        let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        assert_eq!(mat.trace(), 34.0);
+
+        //test mult_vec_transposed
+        let mat = Mat4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        let vec = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(Matrix::mult_vec_transposed(mat, vec), mat.transpose() * vec);
+        assert_eq!(vec * mat, Vec4::new(90.0, 100.0, 110.0, 120.0));
+
+    }
+
+    #[test]
+    fn test_mat_3_transforms() {
+        let quarter = 90.0f64.to_radians();
+
+        //test from_scale
+        let mat = Mat3::from_scale(Vec3::new(2.0, 3.0, 4.0));
+        assert_eq!(mat * Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 3.0, 4.0));
+
+        //test from_rotation
+        let res = Mat3::from_rotation_x(Rad(quarter)) * Vec3::unit_y();
+        assert!((res - Vec3::unit_z()).magnitude() < 1e-9);
+        let res = Mat3::from_rotation_y(Rad(quarter)) * Vec3::unit_z();
+        assert!((res - Vec3::unit_x()).magnitude() < 1e-9);
+        let res = Mat3::from_rotation_z(Rad(quarter)) * Vec3::unit_x();
+        assert!((res - Vec3::unit_y()).magnitude() < 1e-9);
+
+        //test from_axis_angle
+        let mat = Mat3::from_axis_angle(Unit::new_normalize(Vec3::new(0.0, 0.0, 2.0)).unwrap(), Rad(quarter));
+        let res = mat - Mat3::from_rotation_z(Rad(quarter));
+        assert!(res.as_ref().iter().all(|e| e.abs() < 1e-9));
+        let res = Mat3::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 1.0, 1.0)).unwrap(), Deg(120.0f64)) * Vec3::unit_x();
+        assert!((res - Vec3::unit_y()).magnitude() < 1e-9);
+
+        //test from_shear
+        let mat = Mat3::from_shear(1.0, 0.0, 0.0, 2.0, 0.0, 0.0);
+        assert_eq!(mat * Vec3::new(1.0, 1.0, 1.0), Vec3::new(2.0, 3.0, 1.0));
+
+        //test from_reflection
+        let mat = Mat3::from_reflection(Vec3::y_axis());
+        assert_eq!(mat * Vec3::new(1.0, 2.0, 3.0), Vec3::new(1.0, -2.0, 3.0));
+        assert_eq!(mat.determinant(), -1.0);
+
+        //test 2d transforms
+        let point = Vec3::new(1.0, 2.0, 1.0);
+        assert_eq!(Mat3::from_translation_2d(Vec2::new(3.0, -1.0)) * point, Vec3::new(4.0, 1.0, 1.0));
+        assert_eq!(Mat3::from_scale_2d(Vec2::new(3.0, -1.0)) * point, Vec3::new(3.0, -2.0, 1.0));
+        assert!((Mat3::from_rotation_2d(Rad(quarter)) * point - Vec3::new(-2.0, 1.0, 1.0)).magnitude() < 1e-9);
+        assert_eq!(Mat3::from_shear_2d(1.0, 0.0) * point, Vec3::new(3.0, 2.0, 1.0));
+        assert_eq!(Mat3::from_reflection_2d(Vec2::x_axis()) * point, Vec3::new(-1.0, 2.0, 1.0));
+
+        //test composition
+        let mat = Mat3::from_translation_2d(Vec2::new(1.0, 0.0)) * Mat3::from_rotation_2d(Rad(quarter));
+        assert!((mat * Vec3::new(1.0, 0.0, 1.0) - Vec3::new(1.0, 1.0, 1.0)).magnitude() < 1e-9);
+        assert_eq!(mat.get_position(), Vec3::new(1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_mat_4_transforms() {
+        let quarter = 90.0f64.to_radians();
+        let point = Vec4::new(1.0, 2.0, 3.0, 1.0);
+
+        //test from_mat3
+        let mat = Mat4::from_mat3(Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+        assert_eq!(mat, Mat4::new(1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0, 7.0, 8.0, 9.0, 0.0, 0.0, 0.0, 0.0, 1.0));
+
+        //test from_translation
+        let mat = Mat4::from_translation(Vec3::new(1.0, -1.0, 2.0));
+        assert_eq!(mat * point, Vec4::new(2.0, 1.0, 5.0, 1.0));
+        assert_eq!(mat * Vec4::new(1.0, 2.0, 3.0, 0.0), Vec4::new(1.0, 2.0, 3.0, 0.0));
+
+        //test from_scale
+        let mat = Mat4::from_scale(Vec3::new(2.0, 3.0, 4.0));
+        assert_eq!(mat * point, Vec4::new(2.0, 6.0, 12.0, 1.0));
+
+        //test from_rotation
+        let res = Mat4::from_rotation_x(Rad(quarter)) * point;
+        assert!((res - Vec4::new(1.0, -3.0, 2.0, 1.0)).magnitude() < 1e-9);
+        let res = Mat4::from_rotation_y(Rad(quarter)) * point;
+        assert!((res - Vec4::new(3.0, 2.0, -1.0, 1.0)).magnitude() < 1e-9);
+        let res = Mat4::from_rotation_z(Rad(quarter)) * point;
+        assert!((res - Vec4::new(-2.0, 1.0, 3.0, 1.0)).magnitude() < 1e-9);
+        let res = Mat4::from_axis_angle(Vec3::x_axis(), Rad(quarter)) * point;
+        assert!((res - Vec4::new(1.0, -3.0, 2.0, 1.0)).magnitude() < 1e-9);
+
+        //test from_shear
+        let mat = Mat4::from_shear(0.0, 0.0, 0.0, 0.0, 1.0, 1.0);
+        assert_eq!(mat * point, Vec4::new(1.0, 2.0, 6.0, 1.0));
+
+        //test from_reflection
+        let mat = Mat4::from_reflection(Vec4::new(0.0, 0.0, 2.0, -2.0)).unwrap();
+        assert_eq!(mat * point, Vec4::new(1.0, 2.0, -1.0, 1.0));
+        assert_eq!(mat * Vec4::new(5.0, 5.0, 1.0, 1.0), Vec4::new(5.0, 5.0, 1.0, 1.0));
+        assert_eq!(Mat4::from_reflection(Vec4::new(0.0, 0.0, 0.0, 1.0)), None);
+
+        //test composition
+        let mat = Mat4::from_translation(Vec3::new(0.0, 0.0, 1.0)) * Mat4::from_rotation_z(Rad(quarter)) * Mat4::from_scale(Vec3::new(2.0, 2.0, 2.0));
+        assert!((mat * Vec4::new(1.0, 0.0, 0.0, 1.0) - Vec4::new(0.0, 2.0, 1.0, 1.0)).magnitude() < 1e-9);
+        assert!((mat.inverse_affine() * mat * point - point).magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn test_mat_4_decompose() {
+        let mat_eq = |a: Mat4<f64>, b: Mat4<f64>| (a - b).as_ref().iter().all(|e| e.abs() < 1e-9);
+        let rotation = Unit::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 2.0, 2.0)).unwrap(), Deg(60.0f64));
+        let translation = Vec3::new(1.0, -2.0, 3.0);
+
+        //test from_scale_rotation_translation
+        let mat = Mat4::from_scale_rotation_translation(Vec3::new(2.0, 3.0, 4.0), rotation, translation);
+        let expected = Mat4::from_translation(translation) * Mat4::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 2.0, 2.0)).unwrap(), Deg(60.0f64)) * Mat4::from_scale(Vec3::new(2.0, 3.0, 4.0));
+        assert!(mat_eq(mat, expected));
+
+        //test decompose
+        let (t, r, s) = mat.decompose();
+        assert!((t - translation).magnitude() < 1e-9);
+        assert!((s - Vec3::new(2.0, 3.0, 4.0)).magnitude() < 1e-9);
+        assert!((r.scalar - rotation.scalar).abs() < 1e-9 && (r.vector - rotation.vector).magnitude() < 1e-9);
+
+        //test decompose negative scale
+        let mat = Mat4::from_scale_rotation_translation(Vec3::new(2.0, -3.0, 4.0), rotation, translation);
+        let (t, r, s) = mat.decompose();
+        assert!(s.x < 0.0 && s.y > 0.0 && s.z > 0.0);
+        assert!((s.x * s.y * s.z + 24.0).abs() < 1e-9);
+        assert!(mat_eq(Mat4::from_scale_rotation_translation(s, r, t), mat));
+
+        //test decompose degenerate axes
+        for scale in [Vec3::new(0.0, 3.0, 4.0), Vec3::new(2.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 0.0)] {
+            let mat = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+            let (t, r, s) = mat.decompose();
+            assert!((s - scale).magnitude() < 1e-9);
+            assert!((r.magnitude() - 1.0).abs() < 1e-9);
+            assert!(mat_eq(Mat4::from_scale_rotation_translation(s, r, t), mat));
+        }
+
+        //test decompose tiny uniform scales
+        let mat = Mat4::from_scale(Vec3::new(1e-9f32, 1e-9, 1e-9));
+        let (_, r, s) = mat.decompose();
+        assert_relative_eq!(s, Vec3::new(1e-9, 1e-9, 1e-9));
+        assert_relative_eq!(*r, Quaternion::identity());
+        let mat = Mat4::from_scale_rotation_translation(Vec3::new(1e-16, 2e-16, 3e-16), rotation, translation);
+        let (_, r, s) = mat.decompose();
+        assert_relative_eq!(s, Vec3::new(1e-16, 2e-16, 3e-16), max_relative = 1e-9);
+        assert!(r.dot(&rotation).abs() > 1.0 - 1e-9);
+
+        //test decompose sheared and parallel axes still give a rotation
+        let orthonormal = |r: Unit<Quaternion<f64>>| {
+            let m = Mat3::from_quaternion(r);
+            (m * m.transpose() - Mat3::identity()).as_ref().iter().all(|e| e.abs() < 1e-9) && (m.determinant() - 1.0).abs() < 1e-9
+        };
+        let sheared = Mat4::from_cols(Vec4::new(2.0, 0.0, 0.0, 0.0), Vec4::new(1.0, 1.0, 0.0, 0.0), Vec4::zeroed(), Vec4::unit_w());
+        let (_, r, s) = sheared.decompose();
+        assert!(orthonormal(r));
+        assert_relative_eq!(Mat3::from_quaternion(r), Mat3::identity(), epsilon = 1e-12);
+        assert_relative_eq!(s, Vec3::new(2.0, 2.0f64.sqrt(), 0.0));
+        let parallel = Mat4::from_cols(Vec4::new(0.0, 1.0, 0.0, 0.0), Vec4::new(0.0, 3.0, 0.0, 0.0), Vec4::zeroed(), Vec4::unit_w());
+        let (_, r, s) = parallel.decompose();
+        assert!(orthonormal(r));
+        assert_relative_eq!(Mat3::from_quaternion(r) * Vec3::unit_x(), Vec3::unit_y(), epsilon = 1e-12);
+        assert_relative_eq!(s, Vec3::new(1.0, 3.0, 0.0));
+
+        //test decompose identity
+        let (t, r, s) = Mat4::<f64>::identity().decompose();
+        assert_eq!(t, Vec3::zeroed());
+        assert_eq!(r, Unit::identity());
+        assert_eq!(s, Vec3::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_mat_4_projections() {
+        let project = |mat: Mat4<f64>, point: Vec3<f64>| {
+            let clip = mat * Vec4::new(point.x, point.y, point.z, 1.0);
+            Vec3::new(clip.x / clip.w, clip.y / clip.w, clip.z / clip.w)
+        };
+        let unproject = |mat: Mat4<f64>, ndc: Vec3<f64>| {
+            let view = mat.inverse() * Vec4::new(ndc.x, ndc.y, ndc.z, 1.0);
+            Vec3::new(view.x / view.w, view.y / view.w, view.z / view.w)
+        };
+        let fov = Deg(90.0f64);
+
+        //test perspective_rh
+        let mat = Mat4::perspective_rh(fov, 2.0, 1.0, 100.0, ClipDepth::ZeroToOne);
+        assert_relative_eq!(mat, Mat4::perspective_rh(Rad(std::f64::consts::FRAC_PI_2), 2.0, 1.0, 100.0, ClipDepth::ZeroToOne));
+        assert!((project(mat, Vec3::new(2.0, 1.0, -1.0)) - Vec3::new(1.0, 1.0, 0.0)).magnitude() < 1e-9);
+        assert!((project(mat, Vec3::new(-200.0, -100.0, -100.0)) - Vec3::new(-1.0, -1.0, 1.0)).magnitude() < 1e-9);
+        let point = Vec3::new(3.0, -2.0, -25.0);
+        assert!((unproject(mat, project(mat, point)) - point).magnitude() < 1e-9);
+        let mat = Mat4::perspective_rh(fov, 2.0, 1.0, 100.0, ClipDepth::NegOneToOne);
+        assert!((project(mat, Vec3::new(0.0, 0.0, -1.0)).z + 1.0).abs() < 1e-9);
+        assert!((project(mat, Vec3::new(0.0, 0.0, -100.0)).z - 1.0).abs() < 1e-9);
+        assert!((unproject(mat, project(mat, point)) - point).magnitude() < 1e-9);
+
+        //test perspective_lh
+        let mat = Mat4::perspective_lh(fov, 2.0, 1.0, 100.0, ClipDepth::ZeroToOne);
+        assert!((project(mat, Vec3::new(2.0, 1.0, 1.0)) - Vec3::new(1.0, 1.0, 0.0)).magnitude() < 1e-9);
+        assert!((project(mat, Vec3::new(0.0, 0.0, 100.0)).z - 1.0).abs() < 1e-9);
+        let mat = Mat4::perspective_lh(fov, 2.0, 1.0, 100.0, ClipDepth::NegOneToOne);
+        assert!((project(mat, Vec3::new(0.0, 0.0, 1.0)).z + 1.0).abs() < 1e-9);
+        let point = Vec3::new(3.0, -2.0, 25.0);
+        assert!((unproject(mat, project(mat, point)) - point).magnitude() < 1e-9);
+
+        //test frustum
+        let mat = Mat4::frustum_rh(-1.0, 3.0, -2.0, 2.0, 1.0, 10.0, ClipDepth::ZeroToOne);
+        assert!((project(mat, Vec3::new(3.0, 2.0, -1.0)) - Vec3::new(1.0, 1.0, 0.0)).magnitude() < 1e-9);
+        assert!((project(mat, Vec3::new(-10.0, -20.0, -10.0)) - Vec3::new(-1.0, -1.0, 1.0)).magnitude() < 1e-9);
+        let mat = Mat4::frustum_lh(-1.0, 3.0, -2.0, 2.0, 1.0, 10.0, ClipDepth::NegOneToOne);
+        assert!((project(mat, Vec3::new(-1.0, -2.0, 1.0)) - Vec3::new(-1.0, -1.0, -1.0)).magnitude() < 1e-9);
+
+        //test perspective_infinite
+        let mat = Mat4::perspective_infinite_rh(fov, 1.0, 0.5, ClipDepth::ZeroToOne);
+        assert!(project(mat, Vec3::new(0.0, 0.0, -0.5)).z.abs() < 1e-9);
+        assert!((project(mat, Vec3::new(0.0, 0.0, -1e9)).z - 1.0).abs() < 1e-6);
+        let mat = Mat4::perspective_infinite_lh(fov, 1.0, 0.5, ClipDepth::NegOneToOne);
+        assert!((project(mat, Vec3::new(0.0, 0.0, 0.5)).z + 1.0).abs() < 1e-9);
+        assert!((project(mat, Vec3::new(0.0, 0.0, 1e9)).z - 1.0).abs() < 1e-6);
+
+        //test perspective_reverse_z
+        let mat = Mat4::perspective_reverse_z_rh(fov, 1.0, 1.0, 100.0);
+        assert!((project(mat, Vec3::new(0.0, 0.0, -1.0)).z - 1.0).abs() < 1e-9);
+        assert!(project(mat, Vec3::new(0.0, 0.0, -100.0)).z.abs() < 1e-9);
+        let mat = Mat4::perspective_reverse_z_lh(fov, 1.0, 1.0, 100.0);
+        let point = Vec3::new(3.0, -2.0, 25.0);
+        assert!((unproject(mat, project(mat, point)) - point).magnitude() < 1e-9);
+        let mat = Mat4::perspective_infinite_reverse_z_rh(fov, 1.0, 1.0);
+        assert!((project(mat, Vec3::new(0.0, 0.0, -1.0)).z - 1.0).abs() < 1e-9);
+        assert!(project(mat, Vec3::new(0.0, 0.0, -1e9)).z.abs() < 1e-6);
+        let mat = Mat4::perspective_infinite_reverse_z_lh(fov, 1.0, 1.0);
+        assert!((project(mat, Vec3::new(0.0, 0.0, 1.0)).z - 1.0).abs() < 1e-9);
+
+        //test orthographic
+        let mat = Mat4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0, ClipDepth::ZeroToOne);
+        assert!((project(mat, Vec3::new(2.0, 1.0, 0.0)) - Vec3::new(1.0, 1.0, 0.0)).magnitude() < 1e-9);
+        assert!((project(mat, Vec3::new(-2.0, -1.0, -10.0)) - Vec3::new(-1.0, -1.0, 1.0)).magnitude() < 1e-9);
+        let mat = Mat4::orthographic_lh(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0, ClipDepth::NegOneToOne);
+        assert!((project(mat, Vec3::new(0.0, 0.0, 0.0)).z + 1.0).abs() < 1e-9);
+        assert!((project(mat, Vec3::new(0.0, 0.0, 10.0)).z - 1.0).abs() < 1e-9);
+        let point = Vec3::new(1.5, -0.5, 4.0);
+        assert!((unproject(mat, project(mat, point)) - point).magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn test_mat_4_views() {
+        let eye = Vec3::new(1.0, 2.0, 5.0);
+        let target = Vec3::new(1.0, 2.0, 0.0);
+        let up = Vec3::unit_y();
+
+        //test look_at_rh
+        let view = Mat4::look_at_rh(eye, target, up).unwrap();
+        assert_eq!(view * Vec4::new(1.0, 2.0, 0.0, 1.0), Vec4::new(0.0, 0.0, -5.0, 1.0));
+        assert_eq!(view * Vec4::new(2.0, 2.0, 5.0, 1.0), Vec4::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(view, Mat4::look_to_rh(eye, target - eye, up).unwrap());
+
+        //test look_at_lh
+        let view = Mat4::look_at_lh(eye, target, up).unwrap();
+        assert_eq!(view * Vec4::new(1.0, 2.0, 0.0, 1.0), Vec4::new(0.0, 0.0, 5.0, 1.0));
+        assert_eq!(view * Vec4::new(0.0, 2.0, 5.0, 1.0), Vec4::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(view, Mat4::look_to_lh(eye, target - eye, up).unwrap());
+
+        //test view_basis_rh
+        let eye = Vec3::new(3.0, -4.0, 2.0);
+        let target = Vec3::new(-1.0, 6.0, 0.5);
+        let view = Mat4::look_at_rh(eye, target, up).unwrap();
+        let (res_eye, forward, right, res_up) = view.view_basis_rh();
+        assert!((res_eye - eye).magnitude() < 1e-9);
+        assert!((forward - (target - eye).normalize()).magnitude() < 1e-9);
+        assert!(right.dot(forward).abs() < 1e-9 && right.dot(up).abs() < 1e-9);
+        assert!((right.cross(res_up) + forward).magnitude() < 1e-9);
+
+        //test view_basis_lh
+        let view = Mat4::look_at_lh(eye, target, up).unwrap();
+        let (res_eye, forward, right, res_up) = view.view_basis_lh();
+        assert!((res_eye - eye).magnitude() < 1e-9);
+        assert!((forward - (target - eye).normalize()).magnitude() < 1e-9);
+        assert!((res_up.cross(right) + forward).magnitude() < 1e-9);
+        assert!((view.inverse_orthonormal().get_position() - Vec4::new(eye.x, eye.y, eye.z, 1.0)).magnitude() < 1e-9);
+
+        //test degenerate views
+        assert_eq!(Mat4::look_at_rh(eye, eye, up), None);
+        assert_eq!(Mat4::look_to_lh(eye, Vec3::zeroed(), up), None);
+        let orthonormal = |view: Mat4<f64>| {
+            let basis = Mat3::from_mat4(view);
+            (basis * basis.transpose() - Mat3::identity()).as_ref().iter().all(|e| e.abs() < 1e-9)
+        };
+        for direction in [Vec3::new(0.0, -3.0, 0.0), Vec3::new(0.0, 2.0, 0.0), Vec3::new(1e-12, 1.0, 0.0)] {
+            let view = Mat4::look_to_rh(eye, direction, up).unwrap();
+            assert!(orthonormal(view) && !view.as_ref().iter().any(|e| e.is_nan()));
+            let (_, forward, _, _) = view.view_basis_rh();
+            assert!((forward - direction.normalize()).magnitude() < 1e-9);
+            let view = Mat4::look_to_lh(eye, direction, up).unwrap();
+            assert!(orthonormal(view) && !view.as_ref().iter().any(|e| e.is_nan()));
+            let (_, forward, _, _) = view.view_basis_lh();
+            assert!((forward - direction.normalize()).magnitude() < 1e-9);
+        }
+        assert!(orthonormal(Mat4::look_to_rh(eye, Vec3::unit_z(), Vec3::zeroed()).unwrap()));
+    }
+
+    #[test]
+    fn test_quaternion() {
+        //test identity
+        let q = Quaternion::<f64>::identity();
+        assert_eq!(q, Quaternion::new(1.0, Vec3::zeroed()));
+        assert_eq!(q * Quaternion::new(0.0, Vec3::new(1.0, 2.0, 3.0)), Quaternion::new(0.0, Vec3::new(1.0, 2.0, 3.0)));
+
+        //test from_axis_angle
+        let q = Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(0.0, 0.0, 2.0)).unwrap(), Deg(90.0f64));
+        assert!((q.scalar - 0.5f64.sqrt()).abs() < 1e-9);
+        assert!((q.vector - Vec3::new(0.0, 0.0, 0.5f64.sqrt())).magnitude() < 1e-9);
+        assert!((q.magnitude() - 1.0).abs() < 1e-9);
+
+        //test to_axis_angle
+        let (axis, Rad(angle)) = Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, -2.0, 2.0)).unwrap(), Rad(2.5)).to_axis_angle();
+        assert!((*axis - Vec3::new(1.0, -2.0, 2.0).normalize()).magnitude() < 1e-9);
+        assert!((angle - 2.5).abs() < 1e-9);
+        let (axis, angle) = Quaternion::from_axis_angle(Vec3::y_axis(), Deg(30.0f64)).to_axis_angle();
+        let angle = Deg::from(angle).0;
+        assert!((*axis - Vec3::unit_y()).magnitude() < 1e-9);
+        assert!((angle - 30.0).abs() < 1e-9);
+        let (axis, Rad(angle)) = Quaternion::<f64>::identity().to_axis_angle();
+        assert_eq!((axis, angle), (Vec3::x_axis(), 0.0));
+
+        //test rotate_on_axis
+        let res = rotate_on_axis(Vec3::new(0.0, 1.0, 0.0), Deg(-90.0), Unit::new_normalize(Vec3::new(2.0, 0.0, 0.0)).unwrap());
+        assert!((res - Vec3::new(0.0, 0.0, -1.0)).magnitude() < 1e-9);
+
+        //test mul vec3
+        let q = Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, -2.0, 0.5)).unwrap(), Rad(2.2));
+        let vec = Vec3::new(3.0, 1.0, -2.0);
+        let sandwich = (q * Quaternion::new(0.0, vec) * q.inverse()).vector;
+        assert!((q * vec - sandwich).magnitude() < 1e-9);
+        assert!((Quaternion::from_axis_angle(Vec3::z_axis(), Deg(90.0f64)) * Vec3::unit_x() - Vec3::unit_y()).magnitude() < 1e-9);
+        assert_eq!(Quaternion::identity() * vec, vec);
+
+        //test rotate_slice
+        let mut vectors = [Vec3::unit_x(), Vec3::unit_y(), vec];
+        q.rotate_slice(&mut vectors);
+        assert_eq!(vectors, [q * Vec3::unit_x(), q * Vec3::unit_y(), q * vec]);
+    }
+
+    #[test]
+    fn test_quaternion_interpolation() {
+        let quat_eq = |a: Quaternion<f64>, b: Quaternion<f64>| (a.scalar - b.scalar).abs() < 1e-9 && (a.vector - b.vector).magnitude() < 1e-9;
+        let from = Quaternion::identity();
+        let to = Quaternion::from_axis_angle(Vec3::z_axis(), Deg(90.0f64));
+
+        //test dot
+        assert_eq!(from.dot(&from), 1.0);
+        assert!((from.dot(&to) - 45.0f64.to_radians().cos()).abs() < 1e-9);
+
+        //test slerp
+        assert!(quat_eq(from.slerp(&to, 0.0), from));
+        assert!(quat_eq(from.slerp(&to, 0.5), Quaternion::from_axis_angle(Vec3::z_axis(), Deg(45.0f64))));
+        assert!(quat_eq(from.slerp(&to, 0.25), Quaternion::from_axis_angle(Vec3::z_axis(), Deg(22.5f64))));
+        assert!(quat_eq(from.slerp(&to, 1.0), to));
+
+        //test slerp shortest path
+        assert!(quat_eq(from.slerp(&(to * -1.0), 0.5), from.slerp(&to, 0.5)));
+
+        //test slerp nearly parallel
+        let close = Quaternion::from_axis_angle(Vec3::x_axis(), Rad(1e-12));
+        let res = from.slerp(&close, 0.5);
+        assert!(res.scalar.is_finite() && (res.magnitude() - 1.0).abs() < 1e-9);
+        assert!(quat_eq(from.slerp(&from, 0.3), from));
+
+        //test nlerp
+        assert!(quat_eq(from.nlerp(&to, 0.5), from.slerp(&to, 0.5)));
+        assert!(quat_eq(from.nlerp(&(to * -1.0), 1.0), to));
+        assert!((from.nlerp(&to, 0.3).magnitude() - 1.0).abs() < 1e-9);
+
+        //test squad
+        let keys: Vec<_> = (0..4).map(|i| Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 1.0, 0.0)).unwrap(), Rad(0.5 * i as f64))).collect();
+        let a = Quaternion::squad_control_point(&keys[0], &keys[1], &keys[2]);
+        let b = Quaternion::squad_control_point(&keys[1], &keys[2], &keys[3]);
+        assert!(quat_eq(a, keys[1]) && quat_eq(b, keys[2]));
+        assert!(quat_eq(keys[1].squad(&a, &b, &keys[2], 0.0), keys[1]));
+        assert!(quat_eq(keys[1].squad(&a, &b, &keys[2], 1.0), keys[2]));
+        assert!(quat_eq(keys[1].squad(&a, &b, &keys[2], 0.4), keys[1].slerp(&keys[2], 0.4)));
+
+        let keys = [from, to, Quaternion::from_axis_angle(Vec3::x_axis(), Rad(1.0)), Quaternion::from_axis_angle(Vec3::y_axis(), Rad(0.3))];
+        let a = Quaternion::squad_control_point(&keys[0], &keys[1], &keys[2]);
+        let b = Quaternion::squad_control_point(&keys[1], &keys[2], &keys[3]);
+        assert!(quat_eq(keys[1].squad(&a, &b, &keys[2], 0.0), keys[1]));
+        assert!(quat_eq(keys[1].squad(&a, &b, &keys[2], 1.0), keys[2]));
+        assert!((keys[1].squad(&a, &b, &keys[2], 0.6).magnitude() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_quaternion_exp_ln() {
+        let quat_eq = |a: Quaternion<f64>, b: Quaternion<f64>| (a.scalar - b.scalar).abs() < 1e-9 && (a.vector - b.vector).magnitude() < 1e-9;
+        let q = Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 2.0, -1.0)).unwrap(), Rad(1.3));
+
+        //test exp
+        let res = Quaternion::new(0.0, Vec3::unit_z() * (45.0f64.to_radians())).exp();
+        assert!(quat_eq(res, Quaternion::from_axis_angle(Vec3::z_axis(), Deg(90.0f64))));
+        let res = Quaternion::new(1.0, Vec3::zeroed()).exp();
+        assert!(quat_eq(res, Quaternion::new(1.0f64.exp(), Vec3::zeroed())));
+
+        //test ln
+        let res = q.ln();
+        assert!(res.scalar.abs() < 1e-9);
+        assert!((res.vector - Vec3::new(1.0, 2.0, -1.0).normalize() * 0.65).magnitude() < 1e-9);
+        assert!(quat_eq(res.exp(), q));
+        let q2 = Quaternion::new(2.0, Vec3::new(0.5, -1.0, 3.0));
+        assert!(quat_eq(q2.ln().exp(), q2));
+        assert!(quat_eq(Quaternion::identity().ln(), Quaternion::new(0.0, Vec3::zeroed())));
+        let neg = Quaternion::new(-1.0, Vec3::zeroed());
+        assert!(quat_eq(neg.ln(), Quaternion::new(0.0, Vec3::unit_x() * std::f64::consts::PI)));
+        assert!(quat_eq(neg.ln().exp(), neg));
+        let near_neg = Quaternion::new(-1.0, Vec3::new(0.0, 1e-20, 0.0));
+        assert!(quat_eq(near_neg.ln().exp(), near_neg));
+        let res = Quaternion::new(0.0, Vec3::zeroed()).ln();
+        assert!(res.scalar == f64::NEG_INFINITY && res.vector == Vec3::zeroed());
+
+        //test powf
+        assert!(quat_eq(q.powf(0.5), Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 2.0, -1.0)).unwrap(), Rad(0.65))));
+        assert!(quat_eq(q.powf(2.0), q * q));
+        assert!(quat_eq(q.powf(0.0), Quaternion::identity()));
+        assert!(quat_eq(Quaternion::identity().powf(0.3), Quaternion::identity()));
+        let half_turn = Quaternion::from_axis_angle(Vec3::y_axis(), Rad(std::f64::consts::PI));
+        assert!(quat_eq(half_turn.powf(0.5), Quaternion::from_axis_angle(Vec3::y_axis(), Deg(90.0))));
+        assert!(quat_eq(half_turn.powf(2.0), neg));
+        let root = neg.powf(0.5);
+        assert!(quat_eq(root * root, neg));
+
+        //test integrate
+        let velocity = Vec3::new(0.0, 0.0, 90.0f64.to_radians());
+        let mut res = Quaternion::identity();
+        for _ in 0..10 {
+            res = res.integrate(velocity, 0.1);
+        }
+        assert!(quat_eq(res, Quaternion::from_axis_angle(Vec3::z_axis(), Deg(90.0f64))));
+        assert!((res.magnitude() - 1.0).abs() < 1e-12);
+
+        //test integrate world and body frames
+        let start = Quaternion::from_axis_angle(Vec3::x_axis(), Deg(90.0f64));
+        let world = start.integrate(velocity, 1.0);
+        let body = start.integrate_body(velocity, 1.0);
+        assert!((world * Vec3::unit_x() - Vec3::unit_y()).magnitude() < 1e-9);
+        assert!((body * Vec3::unit_x() - start * Vec3::unit_y()).magnitude() < 1e-9);
+        assert!(quat_eq(body, start.integrate(start * velocity, 1.0)));
+    }
+
+    #[test]
+    fn test_quaternion_construction() {
+        let quat_eq = |a: Quaternion<f64>, b: Quaternion<f64>| (a.scalar - b.scalar).abs() < 1e-9 && (a.vector - b.vector).magnitude() < 1e-9;
+        let vec_eq = |a: Vec3<f64>, b: Vec3<f64>| (a - b).magnitude() < 1e-9;
+
+        //test swing_twist
+        let twist = Quaternion::from_axis_angle(Vec3::y_axis(), Rad(0.8));
+        let swing = Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 0.0, 1.0)).unwrap(), Rad(0.5));
+        let (res_swing, res_twist) = (swing * twist).swing_twist(Unit::new_normalize(Vec3::new(0.0, 3.0, 0.0)).unwrap());
+        assert!(quat_eq(res_swing, swing) && quat_eq(res_twist, twist));
+        assert!(quat_eq(res_swing * res_twist, swing * twist));
+        let q = Quaternion::from_euler(EulerRot::ZXY, Rad(0.3), Rad(-1.2), Rad(2.0));
+        let (res_swing, res_twist) = q.swing_twist(Unit::new_normalize(Vec3::new(1.0, 1.0, 1.0)).unwrap());
+        assert!(quat_eq(res_swing * res_twist, q));
+        assert!(res_twist.vector.cross(Vec3::new(1.0, 1.0, 1.0)).magnitude() < 1e-9);
+        assert!(res_swing.vector.dot(Vec3::new(1.0, 1.0, 1.0)).abs() < 1e-9);
+        let half_turn = Quaternion::from_axis_angle(Vec3::x_axis(), Deg(180.0f64));
+        let (res_swing, res_twist) = half_turn.swing_twist(Vec3::y_axis());
+        assert!(quat_eq(res_twist, Quaternion::identity()) && quat_eq(res_swing, half_turn));
+
+        //test from_rotation_arc
+        let from = Vec3::new(1.0, 2.0, 3.0);
+        let to = Vec3::new(-2.0, 0.5, 1.0);
+        let q = Quaternion::from_rotation_arc(from, to);
+        assert!(vec_eq(q * from.normalize(), to.normalize()));
+        assert!(q.vector.dot(from).abs() < 1e-9 && q.vector.dot(to).abs() < 1e-9);
+        assert!(quat_eq(Quaternion::from_rotation_arc(from, from * 2.0), Quaternion::identity()));
+        for dir in [Vec3::unit_x(), Vec3::unit_y(), Vec3::new(1.0, -1.0, 0.5)] {
+            let q = Quaternion::from_rotation_arc(dir, dir * -1.0);
+            assert!(vec_eq(q * dir, dir * -1.0));
+            assert!((q.magnitude() - 1.0).abs() < 1e-9);
+        }
+
+        //test look_rotation
+        let forward = Vec3::new(1.0, 0.0, 1.0);
+        let q = Quaternion::look_rotation(forward, Vec3::unit_y());
+        assert!(vec_eq(q * Vec3::unit_z(), forward.normalize()));
+        assert!(vec_eq(q * Vec3::unit_y(), Vec3::unit_y()));
+        let q = Quaternion::look_rotation(Vec3::new(0.0, 2.0, 0.0), Vec3::unit_y());
+        assert!(vec_eq(q * Vec3::unit_z(), Vec3::unit_y()));
+        assert!((q.magnitude() - 1.0).abs() < 1e-9);
+        let q = Quaternion::look_rotation(Vec3::new(0.3, -1.0, 2.0), Vec3::new(0.0, 1.0, 0.2));
+        assert!(vec_eq(q * Vec3::unit_z(), Vec3::new(0.3, -1.0, 2.0).normalize()));
+        assert!((q * Vec3::unit_x()).dot(Vec3::new(0.0, 1.0, 0.2)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_quaternion_average() {
+        let quat_eq = |a: Quaternion<f64>, b: Quaternion<f64>| (a.scalar - b.scalar).abs() < 1e-9 && (a.vector - b.vector).magnitude() < 1e-9;
+        let mean = Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 2.0, 3.0)).unwrap(), Rad(2.0));
+        let offsets = [
+            Quaternion::from_axis_angle(Vec3::x_axis(), Rad(0.4)),
+            Quaternion::from_axis_angle(Vec3::x_axis(), Rad(-0.4)),
+            Quaternion::from_axis_angle(Vec3::y_axis(), Rad(0.7)),
+            Quaternion::from_axis_angle(Vec3::y_axis(), Rad(-0.7)),
+        ];
+        let rotations: Vec<_> = offsets.iter().map(|o| mean * *o).collect();
+
+        //test average
+        assert!(quat_eq(Quaternion::average(&rotations).unwrap(), mean));
+        let flipped: Vec<_> = rotations.iter().enumerate().map(|(i, q)| if i % 2 == 0 { *q * -1.0 } else { *q }).collect();
+        let res = Quaternion::average(&flipped).unwrap();
+        assert!((res.dot(&mean).abs() - 1.0).abs() < 1e-9);
+        assert_eq!(Quaternion::<f64>::average(&[]), None);
+
+        //test weighted_average
+        let a = Quaternion::from_axis_angle(Vec3::z_axis(), Rad(0.2));
+        let b = Quaternion::from_axis_angle(Vec3::z_axis(), Rad(1.4));
+        assert!(quat_eq(Quaternion::weighted_average(&[a, b], &[1.0, 1.0]).unwrap(), a.slerp(&b, 0.5)));
+        assert!(quat_eq(Quaternion::weighted_average(&[a, b * -1.0], &[2.0, 2.0]).unwrap(), a.slerp(&b, 0.5)));
+        assert!(quat_eq(Quaternion::weighted_average(&[a, b], &[1.0, 0.0]).unwrap(), a));
+        let (axis, Rad(angle)) = Quaternion::weighted_average(&[a, b], &[1.0, 3.0]).unwrap().to_axis_angle();
+        assert!((*axis - Vec3::unit_z()).magnitude() < 1e-9);
+        assert!(angle > 0.8 && angle < 1.4);
+        assert_eq!(Quaternion::weighted_average(&[a, b], &[0.0, 0.0]), None);
+        assert_eq!(Quaternion::weighted_average(&[a, b], &[1.0]), None);
+        assert_eq!(Quaternion::<f64>::weighted_average(&[], &[]), None);
+        assert_eq!(Quaternion::<f64>::average(&[]), None);
+        let x = Quaternion::from_axis_angle(Vec3::x_axis(), Deg(180.0));
+        let y = Quaternion::from_axis_angle(Vec3::y_axis(), Deg(180.0));
+        assert_eq!(Quaternion::weighted_average(&[x, y], &[1.0, 1.0]), None);
+        assert!(quat_eq(Quaternion::weighted_average(&[x, y], &[1.0, 1.2]).unwrap(), y));
+        assert!(quat_eq(Quaternion::weighted_average(&[x, y], &[3.0, 1.0]).unwrap(), x));
+        let spread: Vec<_> = [(1.0, 0.0, 0.0, 0.9), (0.0, 1.0, 0.0, -0.8), (0.0, 0.0, 1.0, 1.0), (1.0, 1.0, 0.0, 0.7)]
+            .iter()
+            .map(|(x, y, z, t)| Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(*x, *y, *z)).unwrap(), Rad(*t)))
+            .collect();
+        let res = Quaternion::average(&spread).unwrap();
+        assert!((res.magnitude() - 1.0).abs() < 1e-12);
+        let cost = |q: Quaternion<f64>| spread.iter().map(|r| 1.0 - q.dot(r).powi(2)).sum::<f64>();
+        for perturb in [Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z()] {
+            let nudged = res * Quaternion::from_axis_angle(Unit::new_normalize(perturb).unwrap(), Rad(1e-3));
+            assert!(cost(res) < cost(nudged));
+        }
+        let spread32: Vec<_> = spread.iter().map(|q| Quaternion::new(q.scalar as f32, Vec3::new(q.vector.x as f32, q.vector.y as f32, q.vector.z as f32))).collect();
+        let res32 = Quaternion::average(&spread32).unwrap();
+        assert!((res32.dot(&Quaternion::new(res.scalar as f32, Vec3::new(res.vector.x as f32, res.vector.y as f32, res.vector.z as f32))) - 1.0).abs() < 1e-5);
+        assert_eq!(Quaternion::weighted_average(&[a, b], &[2.0, -1.0]), None);
+
+        //test nlerp_average
+        let weights = [1.0; 4];
+        assert!(quat_eq(Quaternion::nlerp_average(&rotations, &weights).unwrap(), mean));
+        assert!(quat_eq(Quaternion::nlerp_average(&flipped, &weights).unwrap(), mean * -1.0));
+        assert!(quat_eq(Quaternion::nlerp_average(&[a, b], &[1.0, 1.0]).unwrap(), a.slerp(&b, 0.5)));
+        let spread: Vec<_> = [0.05, -0.02, 0.03].iter().map(|t| mean * Quaternion::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, -1.0, 0.0)).unwrap(), Rad(*t))).collect();
+        let weights = [1.0, 2.0, 0.5];
+        let res = Quaternion::nlerp_average(&spread, &weights).unwrap();
+        assert!(res.dot(&Quaternion::weighted_average(&spread, &weights).unwrap()) > 1.0 - 1e-9);
+        assert_eq!(Quaternion::<f64>::nlerp_average(&[], &[]), None);
+        assert_eq!(Quaternion::nlerp_average(&[a, b], &[1.0, 2.0, 3.0]), None);
+        assert_eq!(Quaternion::nlerp_average(&[a, b], &[1.0, -1.0]), None);
+    }
+
+    #[test]
+    fn test_dual_quaternion() {
+        let quat_eq = |a: Quaternion<f64>, b: Quaternion<f64>| (a.scalar - b.scalar).abs() < 1e-9 && (a.vector - b.vector).magnitude() < 1e-9;
+        let dq_eq = |a: DualQuaternion<f64>, b: DualQuaternion<f64>| quat_eq(a.real, b.real) && quat_eq(a.dual, b.dual);
+        let vec_eq = |a: Vec3<f64>, b: Vec3<f64>| (a - b).magnitude() < 1e-9;
+        let rotation = Unit::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, 2.0, -1.0)).unwrap(), Rad(0.9));
+        let translation = Vec3::new(3.0, -1.0, 2.0);
+        let dq = DualQuaternion::from_rotation_translation(rotation, translation);
+        let point = Vec3::new(0.5, 4.0, -2.0);
+
+        //test construction and transforms
+        assert!(quat_eq(dq.rotation(), *rotation));
+        assert!(vec_eq(dq.translation(), translation));
+        assert!(vec_eq(dq * point, rotation * point + translation));
+        assert!(vec_eq(dq.transform_vector(point), rotation * point));
+        assert!(vec_eq(DualQuaternion::from_translation(translation) * point, point + translation));
+        assert!(vec_eq(DualQuaternion::identity() * point, point));
+
+        //test composition and inverse
+        let other = DualQuaternion::from_rotation_translation(Unit::from_axis_angle(Vec3::z_axis(), Rad(-1.3)), Vec3::new(0.0, 2.0, 5.0));
+        assert!(vec_eq((dq * other) * point, dq * (other * point)));
+        assert!(dq_eq(dq * dq.inverse(), DualQuaternion::identity()));
+        assert!(dq_eq(dq.inverse(), dq.conjugate()));
+        assert!(vec_eq(dq.inverse() * (dq * point), point));
+        let scaled = dq * 2.5;
+        assert!(dq_eq(scaled * scaled.inverse(), DualQuaternion::identity()));
+
+        //test conjugates
+        assert!(dq_eq(dq.dual_conjugate(), DualQuaternion::new(*rotation, dq.dual * -1.0)));
+        let p = DualQuaternion::new(Quaternion::identity(), Quaternion::new(0.0, point));
+        let res = dq * p * dq.combined_conjugate();
+        assert!(vec_eq(res.dual.vector, rotation * point + translation));
+
+        //test normalize
+        let mut drifted = dq * 3.0;
+        drifted.dual.scalar += 0.01;
+        drifted.normalize();
+        assert!((drifted.real.magnitude() - 1.0).abs() < 1e-9);
+        assert!(drifted.real.dot(&drifted.dual).abs() < 1e-9);
+
+        //test mat4 conversions
+        let mat = Mat4::from_scale_rotation_translation(Vec3::new(1.0, 1.0, 1.0), rotation, translation);
+        assert!(vec_eq(dq.to_mat4().mult_vec(Vec4::new(point.x, point.y, point.z, 1.0)).into(), dq * point));
+        assert!(dq_eq(DualQuaternion::from_mat4(mat), dq));
+        let scaled = Mat4::from_scale_rotation_translation(Vec3::new(2.0, 0.5, 3.0), rotation, translation);
+        assert!(dq_eq(DualQuaternion::from_mat4(scaled), dq));
+        assert!(dq_eq(DualQuaternion::from_mat4(dq.to_mat4()), dq));
+
+        //test sclerp
+        assert!(dq_eq(dq.sclerp(&other, 0.0), dq));
+        assert!(dq_eq(dq.sclerp(&other, 1.0), other));
+        assert!(dq_eq(dq.sclerp(&(other * -1.0), 1.0), other));
+        let mid = dq.sclerp(&other, 0.5);
+        assert!(quat_eq(mid.rotation(), dq.rotation().slerp(&other.rotation(), 0.5)));
+        assert!(dq_eq(dq.sclerp(&mid, 1.0), mid));
+        let start = DualQuaternion::from_translation(Vec3::new(1.0, 0.0, 0.0));
+        let end = DualQuaternion::from_translation(Vec3::new(3.0, 4.0, 0.0));
+        assert!(vec_eq(start.sclerp(&end, 0.25).translation(), Vec3::new(1.5, 1.0, 0.0)));
+        //a screw about z keeps points on the axis on the axis
+        let screw = DualQuaternion::from_rotation_translation(Unit::from_axis_angle(Vec3::z_axis(), Rad(1.2)), Vec3::new(0.0, 0.0, 4.0));
+        let res = DualQuaternion::identity().sclerp(&screw, 0.5);
+        assert!(vec_eq(res * Vec3::zeroed(), Vec3::new(0.0, 0.0, 2.0)));
+        assert!(quat_eq(res.rotation(), Quaternion::from_axis_angle(Vec3::z_axis(), Rad(0.6))));
+
+        //test sclerp between keyframes with a tiny rotation difference
+        let key1 = DualQuaternion::from_rotation_translation(Unit::from_axis_angle(Vec3::z_axis(), Rad(0.1f32)), Vec3::new(1.0, 2.0, 3.0));
+        let key2 = DualQuaternion::from_rotation_translation(Unit::from_axis_angle(Vec3::z_axis(), Rad(0.1002f32)), Vec3::new(1.5, 2.0, 3.0));
+        assert_relative_eq!(key1.sclerp(&key2, 1.0).real, key2.real, epsilon = 1e-6);
+        assert_relative_eq!(key1.sclerp(&key2, 1.0).translation(), key2.translation(), epsilon = 1e-5);
+        let res = key1.sclerp(&key2, 0.5);
+        assert_relative_eq!(res.real, Quaternion::from_axis_angle(Vec3::z_axis(), Rad(0.1001f32)), epsilon = 1e-6);
+        assert_relative_eq!(res.translation(), Vec3::new(1.25, 2.0, 3.0), epsilon = 1e-4);
+
+        //test blend
+        let res = DualQuaternion::blend(&[dq, other * -1.0], &[1.0, 0.0]).unwrap();
+        assert!(dq_eq(res, dq));
+        let res = DualQuaternion::blend(&[start, end], &[0.5, 0.5]).unwrap();
+        assert!(vec_eq(res.translation(), Vec3::new(2.0, 2.0, 0.0)));
+        let res = DualQuaternion::blend(&[dq, other], &[0.3, 0.7]).unwrap();
+        assert!((res.real.magnitude() - 1.0).abs() < 1e-9);
+        assert_eq!(DualQuaternion::<f64>::blend(&[], &[]), None);
+        assert_eq!(DualQuaternion::blend(&[dq, other], &[1.0]), None);
+        assert_eq!(DualQuaternion::blend(&[dq], &[1.0, 1.0]), None);
+        assert_eq!(DualQuaternion::blend(&[dq, other], &[0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn test_euler() {
+        let orders = [
+            EulerRot::XYZ, EulerRot::XZY, EulerRot::YXZ, EulerRot::YZX, EulerRot::ZXY, EulerRot::ZYX,
+            EulerRot::XYX, EulerRot::XZX, EulerRot::YXY, EulerRot::YZY, EulerRot::ZXZ, EulerRot::ZYZ,
+        ];
+        let same_rotation = |a: Quaternion<f64>, b: Quaternion<f64>| (a.scalar * b.scalar + a.vector.dot(b.vector)).abs() > 1.0 - 1e-9;
+        let half_pi = 90.0f64.to_radians();
+
+        //test from_euler
+        let res = Quaternion::from_euler(EulerRot::XYZ, Rad(0.1), Rad(0.2), Rad(0.3));
+        let expected = Quaternion::from_axis_angle(Vec3::x_axis(), Rad(0.1)) * Quaternion::from_axis_angle(Vec3::y_axis(), Rad(0.2)) * Quaternion::from_axis_angle(Vec3::z_axis(), Rad(0.3));
+        assert!(same_rotation(res, expected));
+        let res = Mat3::from_euler(EulerRot::ZXZ, Rad(0.1), Rad(0.2), Rad(0.3)) - Mat3::from_rotation_z(Rad(0.1)) * Mat3::from_rotation_x(Rad(0.2)) * Mat3::from_rotation_z(Rad(0.3));
+        assert!(res.as_ref().iter().all(|e| e.abs() < 1e-9));
+
+        //test to_euler
+        for order in orders {
+            let proper = matches!(order, EulerRot::XYX | EulerRot::XZX | EulerRot::YXY | EulerRot::YZY | EulerRot::ZXZ | EulerRot::ZYZ);
+            let middle = if proper { 1.1 } else { -0.6 };
+            let q = Unit::from_euler(order, Rad(0.4), Rad(middle), Rad(-2.9));
+            let (Rad(a), Rad(b), Rad(c)) = q.to_euler(order);
+            assert!((a - 0.4).abs() < 1e-9 && (b - middle).abs() < 1e-9 && (c + 2.9).abs() < 1e-9);
+
+            let (Rad(a), Rad(b), Rad(c)) = Mat3::from_quaternion(q).to_euler(order);
+            assert!((a - 0.4).abs() < 1e-9 && (b - middle).abs() < 1e-9 && (c + 2.9).abs() < 1e-9);
+            let (Rad(a), Rad(b), Rad(c)) = Mat4::from_quaternion(q).to_euler(order);
+            assert!(same_rotation(Quaternion::from_euler(order, Rad(a), Rad(b), Rad(c)), *q));
+        }
+
+        //test to_euler gimbal lock
+        for order in orders {
+            let proper = matches!(order, EulerRot::XYX | EulerRot::XZX | EulerRot::YXY | EulerRot::YZY | EulerRot::ZXZ | EulerRot::ZYZ);
+            let locks = if proper { [0.0, 2.0 * half_pi] } else { [half_pi, -half_pi] };
+            for middle in locks {
+                let q = Quaternion::from_euler(order, Rad(0.7), Rad(middle), Rad(0.5));
+                let (Rad(a), Rad(b), Rad(c)) = q.to_euler(order);
+                assert_eq!(c, 0.0);
+                assert!(same_rotation(Quaternion::from_euler(order, Rad(a), Rad(b), Rad(c)), q));
+            }
+        }
+    }
+
+    #[test]
+    fn test_quaternion_matrix_conversions() {
+        let mat_eq = |a: Mat3<f64>, b: Mat3<f64>| (a - b).as_ref().iter().all(|e| e.abs() < 1e-9);
+        let axis_angle = |axis: Vec3<f64>, angle: f64| Unit::from_axis_angle(Unit::new_normalize(axis).unwrap(), Rad(angle));
+
+        //test Mat3::from_quaternion
+        let q = axis_angle(Vec3::unit_z(), 90.0f64.to_radians());
+        assert!(mat_eq(Mat3::from_quaternion(q), Mat3::from_rotation_z(Deg(90.0f64))));
+        let q = axis_angle(Vec3::new(1.0, -2.0, 0.5), 1.2);
+        assert!(mat_eq(Mat3::from_quaternion(q), Mat3::from_axis_angle(Unit::new_normalize(Vec3::new(1.0, -2.0, 0.5)).unwrap(), Rad(1.2))));
+        let vec = Vec3::new(3.0, 1.0, -2.0);
+        assert!((Mat3::from_quaternion(q) * vec - rotate_on_axis(vec, Rad(1.2), Unit::new_normalize(Vec3::new(1.0, -2.0, 0.5)).unwrap())).magnitude() < 1e-9);
+
+        //test Mat4::from_quaternion
+        let mat = Mat4::from_quaternion(q);
+        assert_eq!(Mat3::from_mat4(mat), Mat3::from_quaternion(q));
+        assert_eq!(mat.get_position(), Vec4::unit_w());
+
+        //test Quaternion::from_rotation_matrix
+        let rotations = [
+            axis_angle(Vec3::new(1.0, -2.0, 0.5), 1.2),
+            axis_angle(Vec3::unit_x(), 180.0f64.to_radians()),
+            axis_angle(Vec3::unit_y(), 180.0f64.to_radians()),
+            axis_angle(Vec3::unit_z(), 180.0f64.to_radians()),
+            axis_angle(Vec3::new(1.0, 1.0, 0.0), 179.0f64.to_radians()),
+            axis_angle(Vec3::new(0.0, 1.0, 1.0), 3.0),
+            Unit::identity(),
+        ];
+        for q in rotations {
+            let res = Quaternion::from_rotation_matrix(Mat3::from_quaternion(q));
+            let sign = if res.scalar * q.scalar + res.vector.dot(q.vector) < 0.0 { -1.0 } else { 1.0 };
+            assert!((res.scalar * sign - q.scalar).abs() < 1e-9);
+            assert!((res.vector * sign - q.vector).magnitude() < 1e-9);
+        }
     }
 
     #[test]
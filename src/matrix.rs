@@ -1,27 +1,54 @@
-use super::base::Float;
-use super::vector::{Vec2, Vec3, Vec4};
-use std::ops::{Add, Mul, Sub};
+use super::angle::Rad;
+use super::base::{Float, Number, Signed};
+use super::quaternion::{EulerRot, Quaternion};
+use super::unit::Unit;
+use super::vector::{CrossOps, FloatVectorOps, Vec2, Vec3, Vec4, VectorOps};
+use std::ops::{Add, Div, Mul, Sub};
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Traits
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub trait MatrixOps {
-    type Float;
+    type Scalar;
     type Vector;
 
     fn zeroed() -> Self;
     fn identity() -> Self;
-    fn get_element(&self, index: usize) -> Self::Float;
+    fn get_element(&self, index: usize) -> Self::Scalar;
     fn get_column(&self, col: Column) -> Self::Vector;
     fn get_row(&self, row: Row) -> Self::Vector;
+    // The translation column of a homogeneous transform. Mat3 returns (x, y, 1) for a 2D transform
+    // and Mat4 (x, y, z, 1) for a 3D one. Mat2 is purely linear and always keeps the origin in place,
+    // so it returns zero.
     fn get_position(&self) -> Self::Vector;
 
     fn add(self, other: Self) -> Self;
     fn sub(self, other: Self) -> Self;
-    fn scale(self, other: Self::Float) -> Self;
+    fn scale(self, other: Self::Scalar) -> Self;
     fn mult_mat(self, other: Self) -> Self;
     fn mult_vec(self, other: Self::Vector) -> Self::Vector;
+    fn mult_vec_transposed(self, other: Self::Vector) -> Self::Vector;
+    fn transpose(self) -> Self;
+    fn transpose_mut(&mut self);
+    fn trace(self) -> Self::Scalar;
+}
+
+// Operations whose expansions alternate sign, so need a signed scalar.
+pub trait SignedMatrixOps: MatrixOps {
+    fn determinant(self) -> Self::Scalar;
+    fn cofactor(self) -> Self;
+    fn adjugate(self) -> Self;
+}
+
+// Inversion divides by the determinant, which only makes sense over real numbers.
+pub trait FloatMatrixOps: SignedMatrixOps {
+    fn inverse(self) -> Self;
+    // None when the determinant is within epsilon of zero, relative to the largest element raised
+    // to the matrix size, so uniformly scaled matrices are judged the same at any scale.
+    fn try_inverse(self) -> Option<Self>
+    where
+        Self: Sized;
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -42,20 +69,28 @@ pub enum Row {
     W,
 }
 
+// Range that projected depth is mapped into after the perspective divide. Vulkan, D3D and Metal
+// use ZeroToOne, OpenGL uses NegOneToOne.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClipDepth {
+    ZeroToOne,
+    NegOneToOne,
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Statics
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[allow(non_snake_case)]
 pub mod Matrix {
-    use super::MatrixOps;
+    use super::{FloatMatrixOps, MatrixOps, SignedMatrixOps};
     pub fn add<T: MatrixOps>(matrix1: T, matrix2: T) -> T {
         matrix1.add(matrix2)
     }
     pub fn sub<T: MatrixOps>(matrix1: T, matrix2: T) -> T {
         matrix1.sub(matrix2)
     }
-    pub fn scale<T: MatrixOps>(matrix1: T, scalar: T::Float) -> T {
+    pub fn scale<T: MatrixOps>(matrix1: T, scalar: T::Scalar) -> T {
         matrix1.scale(scalar)
     }
     pub fn mult_mat<T: MatrixOps>(matrix1: T, matrix2: T) -> T {
@@ -64,6 +99,42 @@ pub mod Matrix {
     pub fn mult_vec<T: MatrixOps>(matrix: T, vector: T::Vector) -> T::Vector {
         matrix.mult_vec(vector)
     }
+    pub fn mult_vec_transposed<T: MatrixOps>(matrix: T, vector: T::Vector) -> T::Vector {
+        matrix.mult_vec_transposed(vector)
+    }
+    pub fn transpose<T: MatrixOps>(matrix: T) -> T {
+        matrix.transpose()
+    }
+    pub fn transpose_mut<T: MatrixOps>(matrix: &mut T) {
+        matrix.transpose_mut()
+    }
+    pub fn trace<T: MatrixOps>(matrix: T) -> T::Scalar {
+        matrix.trace()
+    }
+    pub fn determinant<T: SignedMatrixOps>(matrix: T) -> T::Scalar {
+        matrix.determinant()
+    }
+    pub fn cofactor<T: SignedMatrixOps>(matrix: T) -> T {
+        matrix.cofactor()
+    }
+    pub fn adjugate<T: SignedMatrixOps>(matrix: T) -> T {
+        matrix.adjugate()
+    }
+    pub fn inverse<T: FloatMatrixOps>(matrix: T) -> T {
+        matrix.inverse()
+    }
+    pub fn try_inverse<T: FloatMatrixOps>(matrix: T) -> Option<T> {
+        matrix.try_inverse()
+    }
+}
+
+// Whether a determinant is within epsilon of zero, relative to the largest of the `size` x `size`
+// elements it was expanded from raised to `size`.
+fn is_singular<T: Float>(det: T, elements: &[T], size: i32) -> bool {
+    let largest = elements
+        .iter()
+        .fold(T::zero(), |largest, element| largest.max(element.abs()));
+    det.abs() <= T::epsilon() * largest.powi(size)
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -72,14 +143,14 @@ pub mod Matrix {
 
 #[rustfmt::skip]
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
-pub struct Mat2<T: Float> {
+pub struct Mat2<T: Number> {
     pub m00: T, pub m01: T,
     pub m10: T, pub m11: T,
 }
 
 impl<T> Mat2<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
     T: Copy,
 {
@@ -99,13 +170,14 @@ where
 
 impl<T> MatrixOps for Mat2<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
     T: Sub<Output = T>,
     T: Mul<Output = T>,
+    T: Div<Output = T>,
     T: Copy,
 {
-    type Float = T;
+    type Scalar = T;
     type Vector = Vec2<T>;
 
     fn zeroed() -> Self {
@@ -116,7 +188,7 @@ where
         [T::one(), T::zero(), T::zero(), T::one()].into()
     }
 
-    fn get_element(&self, index: usize) -> Self::Float {
+    fn get_element(&self, index: usize) -> Self::Scalar {
         self.as_ref()[index]
     }
 
@@ -137,7 +209,7 @@ where
     }
 
     fn get_position(&self) -> Self::Vector {
-        unimplemented!()
+        Vec2::zeroed()
     }
 
     fn add(self, other: Self) -> Self {
@@ -159,12 +231,89 @@ where
     fn mult_vec(self, other: Self::Vector) -> Self::Vector {
         self * other
     }
+
+    fn mult_vec_transposed(self, other: Self::Vector) -> Self::Vector {
+        other * self
+    }
+
+    fn transpose(self) -> Self {
+        Self {
+            m00: self.m00,
+            m01: self.m10,
+            m10: self.m01,
+            m11: self.m11,
+        }
+    }
+
+    fn transpose_mut(&mut self) {
+        std::mem::swap(&mut self.m01, &mut self.m10);
+    }
+
+    fn trace(self) -> Self::Scalar {
+        self.m00 + self.m11
+    }
 }
 
-impl<T> Add for Mat2<T>
+impl<T> SignedMatrixOps for Mat2<T>
+where
+    T: Signed,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: Copy,
+{
+    fn determinant(self) -> Self::Scalar {
+        self.m00 * self.m11 - self.m01 * self.m10
+    }
+
+    fn cofactor(self) -> Self {
+        Self {
+            m00: self.m11,
+            m01: self.m10 * T::neg_one(),
+            m10: self.m01 * T::neg_one(),
+            m11: self.m00,
+        }
+    }
+
+    fn adjugate(self) -> Self {
+        Self {
+            m00: self.m11,
+            m01: self.m01 * T::neg_one(),
+            m10: self.m10 * T::neg_one(),
+            m11: self.m00,
+        }
+    }
+}
+
+impl<T> FloatMatrixOps for Mat2<T>
 where
     T: Float,
     T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: PartialOrd,
+    T: Copy,
+{
+    fn inverse(self) -> Self {
+        self.adjugate() * (T::one() / self.determinant())
+    }
+
+    fn try_inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if is_singular(det, self.as_ref(), 2) {
+            return None;
+        }
+
+        Some(self.adjugate() * (T::one() / det))
+    }
+}
+
+impl<T> Add for Mat2<T>
+where
+    T: Number,
+    T: Add<Output = T>,
 {
     type Output = Mat2<T>;
 
@@ -180,7 +329,7 @@ where
 
 impl<T> Sub for Mat2<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
 {
     type Output = Mat2<T>;
@@ -197,7 +346,7 @@ where
 
 impl<T> Mul<T> for Mat2<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -215,7 +364,7 @@ where
 
 impl<T> Mul for Mat2<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Add<Output = T>,
     T: Copy,
@@ -234,7 +383,7 @@ where
 
 impl<T> Mul<Vec2<T>> for Mat2<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Add<Output = T>,
     T: Copy,
@@ -249,9 +398,26 @@ where
     }
 }
 
+impl<T> Mul<Mat2<T>> for Vec2<T>
+where
+    T: Number,
+    T: Mul<Output = T>,
+    T: Add<Output = T>,
+    T: Copy,
+{
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: Mat2<T>) -> Self::Output {
+        Self::Output {
+            x: self.x * rhs.m00 + self.y * rhs.m10,
+            y: self.x * rhs.m01 + self.y * rhs.m11,
+        }
+    }
+}
+
 impl<T> AsRef<[T; 4]> for Mat2<T>
 where
-    T: Float,
+    T: Number,
 {
     fn as_ref(&self) -> &[T; 4] {
         unsafe { std::mem::transmute(self) }
@@ -264,7 +430,7 @@ where
 
 #[rustfmt::skip]
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
-pub struct Mat3<T: Float> {
+pub struct Mat3<T: Number> {
     pub m00: T, pub m01: T, pub m02: T,
     pub m10: T, pub m11: T, pub m12: T,
     pub m20: T, pub m21: T, pub m22: T,
@@ -272,7 +438,7 @@ pub struct Mat3<T: Float> {
 
 impl<T> Mat3<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
 {
     #[allow(clippy::too_many_arguments)]
@@ -307,13 +473,14 @@ where
 
 impl<T> MatrixOps for Mat3<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
     T: Sub<Output = T>,
     T: Mul<Output = T>,
+    T: Div<Output = T>,
     T: Copy,
 {
-    type Float = T;
+    type Scalar = T;
     type Vector = Vec3<T>;
 
     fn zeroed() -> Self {
@@ -346,7 +513,7 @@ where
         .into()
     }
 
-    fn get_element(&self, index: usize) -> Self::Float {
+    fn get_element(&self, index: usize) -> Self::Scalar {
         self.as_ref()[index]
     }
 
@@ -369,7 +536,7 @@ where
     }
 
     fn get_position(&self) -> Self::Vector {
-        unimplemented!()
+        self.get_column(Column::Z)
     }
 
     fn add(self, other: Self) -> Self {
@@ -391,12 +558,292 @@ where
     fn mult_vec(self, other: Self::Vector) -> Self::Vector {
         self * other
     }
+
+    fn mult_vec_transposed(self, other: Self::Vector) -> Self::Vector {
+        other * self
+    }
+
+    fn transpose(self) -> Self {
+        Self {
+            m00: self.m00,
+            m01: self.m10,
+            m02: self.m20,
+            m10: self.m01,
+            m11: self.m11,
+            m12: self.m21,
+            m20: self.m02,
+            m21: self.m12,
+            m22: self.m22,
+        }
+    }
+
+    fn transpose_mut(&mut self) {
+        std::mem::swap(&mut self.m01, &mut self.m10);
+        std::mem::swap(&mut self.m02, &mut self.m20);
+        std::mem::swap(&mut self.m12, &mut self.m21);
+    }
+
+    fn trace(self) -> Self::Scalar {
+        self.m00 + self.m11 + self.m22
+    }
 }
 
-impl<T> Add for Mat3<T>
+impl<T> SignedMatrixOps for Mat3<T>
+where
+    T: Signed,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: Copy,
+{
+    fn determinant(self) -> Self::Scalar {
+        self.m00 * (self.m11 * self.m22 - self.m12 * self.m21)
+            + self.m01 * (self.m12 * self.m20 - self.m10 * self.m22)
+            + self.m02 * (self.m10 * self.m21 - self.m11 * self.m20)
+    }
+
+    fn cofactor(self) -> Self {
+        Self {
+            m00: self.m11 * self.m22 - self.m12 * self.m21,
+            m01: self.m12 * self.m20 - self.m10 * self.m22,
+            m02: self.m10 * self.m21 - self.m11 * self.m20,
+            m10: self.m02 * self.m21 - self.m01 * self.m22,
+            m11: self.m00 * self.m22 - self.m02 * self.m20,
+            m12: self.m01 * self.m20 - self.m00 * self.m21,
+            m20: self.m01 * self.m12 - self.m02 * self.m11,
+            m21: self.m02 * self.m10 - self.m00 * self.m12,
+            m22: self.m00 * self.m11 - self.m01 * self.m10,
+        }
+    }
+
+    fn adjugate(self) -> Self {
+        self.cofactor().transpose()
+    }
+}
+
+impl<T> FloatMatrixOps for Mat3<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: PartialOrd,
+    T: Copy,
+{
+    fn inverse(self) -> Self {
+        self.adjugate() * (T::one() / self.determinant())
+    }
+
+    fn try_inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if is_singular(det, self.as_ref(), 3) {
+            return None;
+        }
+
+        Some(self.adjugate() * (T::one() / det))
+    }
+}
+
+impl<T> Mat3<T>
 where
     T: Float,
     T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: PartialOrd,
+    T: Copy,
+{
+    pub fn from_scale(scale: Vec3<T>) -> Self {
+        Self {
+            m00: scale.x,
+            m11: scale.y,
+            m22: scale.z,
+            ..MatrixOps::zeroed()
+        }
+    }
+
+    pub fn from_rotation_x(angle: impl Into<Rad<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self {
+            m11: cos,
+            m12: T::zero() - sin,
+            m21: sin,
+            m22: cos,
+            ..MatrixOps::identity()
+        }
+    }
+
+    pub fn from_rotation_y(angle: impl Into<Rad<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self {
+            m00: cos,
+            m02: sin,
+            m20: T::zero() - sin,
+            m22: cos,
+            ..MatrixOps::identity()
+        }
+    }
+
+    pub fn from_rotation_z(angle: impl Into<Rad<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        Self {
+            m00: cos,
+            m01: T::zero() - sin,
+            m10: sin,
+            m11: cos,
+            ..MatrixOps::identity()
+        }
+    }
+
+    pub fn from_axis_angle(axis: Unit<Vec3<T>>, angle: impl Into<Rad<T>>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+        let t = T::one() - cos;
+        let (x, y, z) = (axis.x, axis.y, axis.z);
+
+        Self {
+            m00: t * x * x + cos,
+            m01: t * x * y - sin * z,
+            m02: t * x * z + sin * y,
+            m10: t * x * y + sin * z,
+            m11: t * y * y + cos,
+            m12: t * y * z - sin * x,
+            m20: t * x * z - sin * y,
+            m21: t * y * z + sin * x,
+            m22: t * z * z + cos,
+        }
+    }
+
+    pub fn from_quaternion(rotation: Unit<Quaternion<T>>) -> Self {
+        let two = T::two();
+        let (w, x, y, z) = (
+            rotation.scalar,
+            rotation.vector.x,
+            rotation.vector.y,
+            rotation.vector.z,
+        );
+
+        Self {
+            m00: T::one() - two * (y * y + z * z),
+            m01: two * (x * y - w * z),
+            m02: two * (x * z + w * y),
+            m10: two * (x * y + w * z),
+            m11: T::one() - two * (x * x + z * z),
+            m12: two * (y * z - w * x),
+            m20: two * (x * z - w * y),
+            m21: two * (y * z + w * x),
+            m22: T::one() - two * (x * x + y * y),
+        }
+    }
+
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<Rad<T>>,
+        b: impl Into<Rad<T>>,
+        c: impl Into<Rad<T>>,
+    ) -> Self {
+        Self::from_quaternion(Unit::<Quaternion<T>>::from_euler(order, a, b, c))
+    }
+
+    pub fn to_euler(self, order: EulerRot) -> (Rad<T>, Rad<T>, Rad<T>) {
+        Quaternion::from_rotation_matrix(self).to_euler(order)
+    }
+
+    pub fn from_mat4(mat: Mat4<T>) -> Self {
+        Self {
+            m00: mat.m00,
+            m01: mat.m01,
+            m02: mat.m02,
+            m10: mat.m10,
+            m11: mat.m11,
+            m12: mat.m12,
+            m20: mat.m20,
+            m21: mat.m21,
+            m22: mat.m22,
+        }
+    }
+
+    // Each factor adds the second named axis into the first, so `xy` gives x' = x + xy * y.
+    pub fn from_shear(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        Self {
+            m01: xy,
+            m02: xz,
+            m10: yx,
+            m12: yz,
+            m20: zx,
+            m21: zy,
+            ..MatrixOps::identity()
+        }
+    }
+
+    // Reflects across the plane through the origin with the given normal.
+    pub fn from_reflection(normal: Unit<Vec3<T>>) -> Self {
+        let n = *normal;
+        let two = T::two();
+
+        Self {
+            m00: T::one() - two * n.x * n.x,
+            m01: T::zero() - two * n.x * n.y,
+            m02: T::zero() - two * n.x * n.z,
+            m10: T::zero() - two * n.y * n.x,
+            m11: T::one() - two * n.y * n.y,
+            m12: T::zero() - two * n.y * n.z,
+            m20: T::zero() - two * n.z * n.x,
+            m21: T::zero() - two * n.z * n.y,
+            m22: T::one() - two * n.z * n.z,
+        }
+    }
+
+    // The _2d constructors build homogeneous transforms for 2D points, stored as (x, y, 1).
+    pub fn from_translation_2d(translation: Vec2<T>) -> Self {
+        Self {
+            m02: translation.x,
+            m12: translation.y,
+            ..MatrixOps::identity()
+        }
+    }
+
+    pub fn from_scale_2d(scale: Vec2<T>) -> Self {
+        Self {
+            m00: scale.x,
+            m11: scale.y,
+            ..MatrixOps::identity()
+        }
+    }
+
+    pub fn from_rotation_2d(angle: impl Into<Rad<T>>) -> Self {
+        Self::from_rotation_z(angle)
+    }
+
+    pub fn from_shear_2d(xy: T, yx: T) -> Self {
+        Self {
+            m01: xy,
+            m10: yx,
+            ..MatrixOps::identity()
+        }
+    }
+
+    // Reflects across the line through the origin with the given normal.
+    pub fn from_reflection_2d(normal: Unit<Vec2<T>>) -> Self {
+        let n = *normal;
+        let two = T::two();
+
+        Self {
+            m00: T::one() - two * n.x * n.x,
+            m01: T::zero() - two * n.x * n.y,
+            m10: T::zero() - two * n.y * n.x,
+            m11: T::one() - two * n.y * n.y,
+            ..MatrixOps::identity()
+        }
+    }
+}
+
+impl<T> Add for Mat3<T>
+where
+    T: Number,
+    T: Add<Output = T>,
 {
     type Output = Mat3<T>;
 
@@ -417,7 +864,7 @@ where
 
 impl<T> Sub for Mat3<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
 {
     type Output = Mat3<T>;
@@ -439,7 +886,7 @@ where
 
 impl<T> Mul<T> for Mat3<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -462,7 +909,7 @@ where
 
 impl<T> Mul for Mat3<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Add<Output = T>,
     T: Copy,
@@ -488,7 +935,7 @@ where
 
 impl<T> Mul<Vec3<T>> for Mat3<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Add<Output = T>,
     T: Copy,
@@ -504,9 +951,27 @@ where
     }
 }
 
+impl<T> Mul<Mat3<T>> for Vec3<T>
+where
+    T: Number,
+    T: Mul<Output = T>,
+    T: Add<Output = T>,
+    T: Copy,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: Mat3<T>) -> Self::Output {
+        Self::Output {
+            x: self.x * rhs.m00 + self.y * rhs.m10 + self.z * rhs.m20,
+            y: self.x * rhs.m01 + self.y * rhs.m11 + self.z * rhs.m21,
+            z: self.x * rhs.m02 + self.y * rhs.m12 + self.z * rhs.m22,
+        }
+    }
+}
+
 impl<T> AsRef<[T; 9]> for Mat3<T>
 where
-    T: Float,
+    T: Number,
 {
     fn as_ref(&self) -> &[T; 9] {
         unsafe { std::mem::transmute(self) }
@@ -519,7 +984,7 @@ where
 
 #[rustfmt::skip]
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
-pub struct Mat4<T: Float> {
+pub struct Mat4<T: Number> {
     pub m00: T, pub m01: T, pub m02: T, pub m03: T,
     pub m10: T, pub m11: T, pub m12: T, pub m13: T,
     pub m20: T, pub m21: T, pub m22: T, pub m23: T,
@@ -528,7 +993,7 @@ pub struct Mat4<T: Float> {
 
 impl<T> Mat4<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
 {
     #[rustfmt::skip]
@@ -571,13 +1036,14 @@ where
 
 impl<T> MatrixOps for Mat4<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
     T: Sub<Output = T>,
     T: Mul<Output = T>,
+    T: Div<Output = T>,
     T: Copy,
 {
-    type Float = T;
+    type Scalar = T;
     type Vector = Vec4<T>;
 
     fn zeroed() -> Self {
@@ -624,7 +1090,7 @@ where
         .into()
     }
 
-    fn get_element(&self, index: usize) -> Self::Float {
+    fn get_element(&self, index: usize) -> Self::Scalar {
         self.as_ref()[index]
     }
 
@@ -632,7 +1098,7 @@ where
         match col {
             Column::X => Vec4::new(self.m00, self.m10, self.m20, self.m30),
             Column::Y => Vec4::new(self.m01, self.m11, self.m21, self.m31),
-            Column::Z => Vec4::new(self.m02, self.m12, self.m22, self.m23),
+            Column::Z => Vec4::new(self.m02, self.m12, self.m22, self.m32),
             Column::W => Vec4::new(self.m03, self.m13, self.m23, self.m33),
         }
     }
@@ -669,12 +1135,717 @@ where
     fn mult_vec(self, other: Vec4<T>) -> Self::Vector {
         self * other
     }
+
+    fn mult_vec_transposed(self, other: Vec4<T>) -> Self::Vector {
+        other * self
+    }
+
+    fn transpose(self) -> Self {
+        Self {
+            m00: self.m00,
+            m01: self.m10,
+            m02: self.m20,
+            m03: self.m30,
+            m10: self.m01,
+            m11: self.m11,
+            m12: self.m21,
+            m13: self.m31,
+            m20: self.m02,
+            m21: self.m12,
+            m22: self.m22,
+            m23: self.m32,
+            m30: self.m03,
+            m31: self.m13,
+            m32: self.m23,
+            m33: self.m33,
+        }
+    }
+
+    fn transpose_mut(&mut self) {
+        std::mem::swap(&mut self.m01, &mut self.m10);
+        std::mem::swap(&mut self.m02, &mut self.m20);
+        std::mem::swap(&mut self.m03, &mut self.m30);
+        std::mem::swap(&mut self.m12, &mut self.m21);
+        std::mem::swap(&mut self.m13, &mut self.m31);
+        std::mem::swap(&mut self.m23, &mut self.m32);
+    }
+
+    fn trace(self) -> Self::Scalar {
+        self.m00 + self.m11 + self.m22 + self.m33
+    }
 }
 
-impl<T> Add for Mat4<T>
+impl<T> SignedMatrixOps for Mat4<T>
+where
+    T: Signed,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: Copy,
+{
+    fn determinant(self) -> Self::Scalar {
+        let (s, c) = self.sub_determinants();
+
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    fn cofactor(self) -> Self {
+        self.adjugate().transpose()
+    }
+
+    fn adjugate(self) -> Self {
+        let (s, c) = self.sub_determinants();
+        Self {
+            m00: self.m11 * c[5] - self.m12 * c[4] + self.m13 * c[3],
+            m01: self.m02 * c[4] - self.m01 * c[5] - self.m03 * c[3],
+            m02: self.m31 * s[5] - self.m32 * s[4] + self.m33 * s[3],
+            m03: self.m22 * s[4] - self.m21 * s[5] - self.m23 * s[3],
+            m10: self.m12 * c[2] - self.m10 * c[5] - self.m13 * c[1],
+            m11: self.m00 * c[5] - self.m02 * c[2] + self.m03 * c[1],
+            m12: self.m32 * s[2] - self.m30 * s[5] - self.m33 * s[1],
+            m13: self.m20 * s[5] - self.m22 * s[2] + self.m23 * s[1],
+            m20: self.m10 * c[4] - self.m11 * c[2] + self.m13 * c[0],
+            m21: self.m01 * c[2] - self.m00 * c[4] - self.m03 * c[0],
+            m22: self.m30 * s[4] - self.m31 * s[2] + self.m33 * s[0],
+            m23: self.m21 * s[2] - self.m20 * s[4] - self.m23 * s[0],
+            m30: self.m11 * c[1] - self.m10 * c[3] - self.m12 * c[0],
+            m31: self.m00 * c[3] - self.m01 * c[1] + self.m02 * c[0],
+            m32: self.m31 * s[1] - self.m30 * s[3] - self.m32 * s[0],
+            m33: self.m20 * s[3] - self.m21 * s[1] + self.m22 * s[0],
+        }
+    }
+}
+
+impl<T> FloatMatrixOps for Mat4<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: PartialOrd,
+    T: Copy,
+{
+    fn inverse(self) -> Self {
+        self.adjugate() * (T::one() / self.determinant())
+    }
+
+    fn try_inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if is_singular(det, self.as_ref(), 4) {
+            return None;
+        }
+
+        Some(self.adjugate() * (T::one() / det))
+    }
+}
+
+impl<T> Mat4<T>
+where
+    T: Signed,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Copy,
+{
+    // 2x2 determinants of the upper two rows (s) and lower two rows (c), shared by the
+    // determinant and adjugate expansions.
+    fn sub_determinants(&self) -> ([T; 6], [T; 6]) {
+        let s = [
+            self.m00 * self.m11 - self.m10 * self.m01,
+            self.m00 * self.m12 - self.m10 * self.m02,
+            self.m00 * self.m13 - self.m10 * self.m03,
+            self.m01 * self.m12 - self.m11 * self.m02,
+            self.m01 * self.m13 - self.m11 * self.m03,
+            self.m02 * self.m13 - self.m12 * self.m03,
+        ];
+        let c = [
+            self.m20 * self.m31 - self.m30 * self.m21,
+            self.m20 * self.m32 - self.m30 * self.m22,
+            self.m20 * self.m33 - self.m30 * self.m23,
+            self.m21 * self.m32 - self.m31 * self.m22,
+            self.m21 * self.m33 - self.m31 * self.m23,
+            self.m22 * self.m33 - self.m32 * self.m23,
+        ];
+
+        (s, c)
+    }
+}
+
+impl<T> Mat4<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: Copy,
+{
+    // Inverse of a matrix whose bottom row is (0, 0, 0, 1). Only the upper 3x3 is inverted and
+    // the translation is carried through it. The upper 3x3 must be invertible, see
+    // try_inverse_affine otherwise.
+    pub fn inverse_affine(self) -> Self {
+        self.affine_inverse(self.affine_determinant())
+    }
+
+    // None when the upper 3x3 is singular, judged the same way as try_inverse.
+    pub fn try_inverse_affine(self) -> Option<Self> {
+        let det = self.affine_determinant();
+        let upper = [
+            self.m00, self.m01, self.m02, self.m10, self.m11, self.m12, self.m20, self.m21,
+            self.m22,
+        ];
+        if is_singular(det, &upper, 3) {
+            return None;
+        }
+
+        Some(self.affine_inverse(det))
+    }
+
+    fn affine_determinant(&self) -> T {
+        self.m00 * (self.m11 * self.m22 - self.m12 * self.m21)
+            + self.m01 * (self.m12 * self.m20 - self.m10 * self.m22)
+            + self.m02 * (self.m10 * self.m21 - self.m11 * self.m20)
+    }
+
+    fn affine_inverse(self, det: T) -> Self {
+        let inv_det = T::one() / det;
+
+        let m00 = (self.m11 * self.m22 - self.m12 * self.m21) * inv_det;
+        let m01 = (self.m02 * self.m21 - self.m01 * self.m22) * inv_det;
+        let m02 = (self.m01 * self.m12 - self.m02 * self.m11) * inv_det;
+        let m10 = (self.m12 * self.m20 - self.m10 * self.m22) * inv_det;
+        let m11 = (self.m00 * self.m22 - self.m02 * self.m20) * inv_det;
+        let m12 = (self.m02 * self.m10 - self.m00 * self.m12) * inv_det;
+        let m20 = (self.m10 * self.m21 - self.m11 * self.m20) * inv_det;
+        let m21 = (self.m01 * self.m20 - self.m00 * self.m21) * inv_det;
+        let m22 = (self.m00 * self.m11 - self.m01 * self.m10) * inv_det;
+
+        Self {
+            m00,
+            m01,
+            m02,
+            m03: T::zero() - (m00 * self.m03 + m01 * self.m13 + m02 * self.m23),
+            m10,
+            m11,
+            m12,
+            m13: T::zero() - (m10 * self.m03 + m11 * self.m13 + m12 * self.m23),
+            m20,
+            m21,
+            m22,
+            m23: T::zero() - (m20 * self.m03 + m21 * self.m13 + m22 * self.m23),
+            m30: T::zero(),
+            m31: T::zero(),
+            m32: T::zero(),
+            m33: T::one(),
+        }
+    }
+
+    // Inverse of a rigid transform (orthonormal rotation plus translation). The rotation is
+    // transposed instead of inverted.
+    pub fn inverse_orthonormal(self) -> Self {
+        Self {
+            m00: self.m00,
+            m01: self.m10,
+            m02: self.m20,
+            m03: T::zero() - (self.m00 * self.m03 + self.m10 * self.m13 + self.m20 * self.m23),
+            m10: self.m01,
+            m11: self.m11,
+            m12: self.m21,
+            m13: T::zero() - (self.m01 * self.m03 + self.m11 * self.m13 + self.m21 * self.m23),
+            m20: self.m02,
+            m21: self.m12,
+            m22: self.m22,
+            m23: T::zero() - (self.m02 * self.m03 + self.m12 * self.m13 + self.m22 * self.m23),
+            m30: T::zero(),
+            m31: T::zero(),
+            m32: T::zero(),
+            m33: T::one(),
+        }
+    }
+}
+
+impl<T> Mat4<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: PartialOrd,
+    T: Default,
+    T: Copy,
+{
+    pub fn from_mat3(mat: Mat3<T>) -> Self {
+        Self {
+            m00: mat.m00,
+            m01: mat.m01,
+            m02: mat.m02,
+            m10: mat.m10,
+            m11: mat.m11,
+            m12: mat.m12,
+            m20: mat.m20,
+            m21: mat.m21,
+            m22: mat.m22,
+            ..MatrixOps::identity()
+        }
+    }
+
+    pub fn from_quaternion(rotation: Unit<Quaternion<T>>) -> Self {
+        Self::from_mat3(Mat3::from_quaternion(rotation))
+    }
+
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<Rad<T>>,
+        b: impl Into<Rad<T>>,
+        c: impl Into<Rad<T>>,
+    ) -> Self {
+        Self::from_mat3(Mat3::from_euler(order, a, b, c))
+    }
+
+    pub fn to_euler(self, order: EulerRot) -> (Rad<T>, Rad<T>, Rad<T>) {
+        Mat3::from_mat4(self).to_euler(order)
+    }
+
+    pub fn from_translation(translation: Vec3<T>) -> Self {
+        Self {
+            m03: translation.x,
+            m13: translation.y,
+            m23: translation.z,
+            ..MatrixOps::identity()
+        }
+    }
+
+    pub fn from_scale(scale: Vec3<T>) -> Self {
+        Self::from_mat3(Mat3::from_scale(scale))
+    }
+
+    pub fn from_rotation_x(angle: impl Into<Rad<T>>) -> Self {
+        Self::from_mat3(Mat3::from_rotation_x(angle))
+    }
+
+    pub fn from_rotation_y(angle: impl Into<Rad<T>>) -> Self {
+        Self::from_mat3(Mat3::from_rotation_y(angle))
+    }
+
+    pub fn from_rotation_z(angle: impl Into<Rad<T>>) -> Self {
+        Self::from_mat3(Mat3::from_rotation_z(angle))
+    }
+
+    pub fn from_axis_angle(axis: Unit<Vec3<T>>, angle: impl Into<Rad<T>>) -> Self {
+        Self::from_mat3(Mat3::from_axis_angle(axis, angle))
+    }
+
+    pub fn from_shear(xy: T, xz: T, yx: T, yz: T, zx: T, zy: T) -> Self {
+        Self::from_mat3(Mat3::from_shear(xy, xz, yx, yz, zx, zy))
+    }
+
+    // The plane is (a, b, c, d) for ax + by + cz + d = 0. None when (a, b, c) is zero.
+    pub fn from_reflection(plane: Vec4<T>) -> Option<Self> {
+        let normal = Vec3::new(plane.x, plane.y, plane.z);
+        let n = Unit::new_normalize(normal)?;
+        let offset = plane.w / normal.magnitude();
+        let two = T::two();
+
+        Some(Self {
+            m03: T::zero() - two * offset * n.x,
+            m13: T::zero() - two * offset * n.y,
+            m23: T::zero() - two * offset * n.z,
+            ..Self::from_mat3(Mat3::from_reflection(n))
+        })
+    }
+
+    // Composes translation * rotation * scale, so scale is applied first.
+    pub fn from_scale_rotation_translation(
+        scale: Vec3<T>,
+        rotation: Unit<Quaternion<T>>,
+        translation: Vec3<T>,
+    ) -> Self {
+        let r = Mat3::from_quaternion(rotation);
+        Self {
+            m00: r.m00 * scale.x,
+            m01: r.m01 * scale.y,
+            m02: r.m02 * scale.z,
+            m03: translation.x,
+            m10: r.m10 * scale.x,
+            m11: r.m11 * scale.y,
+            m12: r.m12 * scale.z,
+            m13: translation.y,
+            m20: r.m20 * scale.x,
+            m21: r.m21 * scale.y,
+            m22: r.m22 * scale.z,
+            m23: translation.z,
+            ..MatrixOps::identity()
+        }
+    }
+
+    // Splits an affine transform into (translation, rotation, scale). A mirrored basis is reported
+    // as a negative x scale. Shear is dropped by orthonormalizing the axes in x, y, z order. Axes
+    // that collapse, to zero length or onto an earlier axis, get an arbitrary orthonormal
+    // direction so the rotation stays valid.
+    pub fn decompose(self) -> (Vec3<T>, Unit<Quaternion<T>>, Vec3<T>) {
+        let translation = Vec3::new(self.m03, self.m13, self.m23);
+        let columns = [
+            Vec3::new(self.m00, self.m10, self.m20),
+            Vec3::new(self.m01, self.m11, self.m21),
+            Vec3::new(self.m02, self.m12, self.m22),
+        ];
+        let mut scale = columns.map(|axis| axis.magnitude());
+
+        // Relative to the longest axis, so uniformly tiny transforms decompose like any other.
+        let largest = scale[0].max(scale[1]).max(scale[2]);
+        let threshold = T::epsilon() * largest;
+
+        let mut axes = [Vec3::zeroed(); 3];
+        let mut valid = [false; 3];
+        for i in 0..3 {
+            if scale[i] <= threshold {
+                scale[i] = T::zero();
+                continue;
+            }
+
+            let mut axis = columns[i];
+            for j in 0..i {
+                if valid[j] {
+                    axis -= axes[j] * axis.dot(axes[j]);
+                }
+            }
+
+            let length = axis.magnitude();
+            if length > T::epsilon().sqrt() * scale[i] {
+                axes[i] = axis * (T::one() / length);
+                valid[i] = true;
+            }
+        }
+
+        match valid.iter().filter(|v| **v).count() {
+            3 => {}
+            2 => {
+                let i = valid.iter().position(|v| !*v).unwrap();
+                axes[i] = axes[(i + 1) % 3].cross(axes[(i + 2) % 3]);
+            }
+            1 => {
+                let i = valid.iter().position(|v| *v).unwrap();
+                let helper = if axes[i].x.abs() < T::one_half() {
+                    Vec3::unit_x()
+                } else {
+                    Vec3::unit_y()
+                };
+                axes[(i + 1) % 3] = axes[i].cross(helper).normalize();
+                axes[(i + 2) % 3] = axes[i].cross(axes[(i + 1) % 3]);
+            }
+            _ => axes = [Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z()],
+        }
+
+        let mut rotation = Mat3::from_cols(axes[0], axes[1], axes[2]);
+        if rotation.determinant() < T::zero() {
+            scale[0] = T::zero() - scale[0];
+            rotation.m00 = T::zero() - rotation.m00;
+            rotation.m10 = T::zero() - rotation.m10;
+            rotation.m20 = T::zero() - rotation.m20;
+        }
+
+        (
+            translation,
+            Unit::from_rotation_matrix(rotation),
+            Vec3::new(scale[0], scale[1], scale[2]),
+        )
+    }
+}
+
+impl<T> Mat4<T>
 where
     T: Float,
     T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Mul<Output = T>,
+    T: Div<Output = T>,
+    T: PartialOrd,
+    T: Copy,
+{
+    // Right handed projections look down -Z, left handed ones down +Z. Every left handed builder
+    // is the right handed one with the view space Z axis flipped.
+    fn flip_z(self) -> Self {
+        Self {
+            m02: T::zero() - self.m02,
+            m12: T::zero() - self.m12,
+            m22: T::zero() - self.m22,
+            m32: T::zero() - self.m32,
+            ..self
+        }
+    }
+
+    pub fn frustum_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth: ClipDepth,
+    ) -> Self {
+        let two = T::two();
+        let (m22, m23) = match depth {
+            ClipDepth::ZeroToOne => (far / (near - far), near * far / (near - far)),
+            ClipDepth::NegOneToOne => {
+                ((far + near) / (near - far), two * far * near / (near - far))
+            }
+        };
+
+        Self {
+            m00: two * near / (right - left),
+            m02: (right + left) / (right - left),
+            m11: two * near / (top - bottom),
+            m12: (top + bottom) / (top - bottom),
+            m22,
+            m23,
+            m32: T::neg_one(),
+            ..MatrixOps::zeroed()
+        }
+    }
+
+    pub fn frustum_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth: ClipDepth,
+    ) -> Self {
+        Self::frustum_rh(left, right, bottom, top, near, far, depth).flip_z()
+    }
+
+    pub fn perspective_rh(
+        fov_y: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        far: T,
+        depth: ClipDepth,
+    ) -> Self {
+        let (sin, cos) = (fov_y.into() * T::one_half()).sin_cos();
+        let top = near * sin / cos;
+        let right = top * aspect;
+
+        Self::frustum_rh(
+            T::zero() - right,
+            right,
+            T::zero() - top,
+            top,
+            near,
+            far,
+            depth,
+        )
+    }
+
+    pub fn perspective_lh(
+        fov_y: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        far: T,
+        depth: ClipDepth,
+    ) -> Self {
+        Self::perspective_rh(fov_y, aspect, near, far, depth).flip_z()
+    }
+
+    pub fn perspective_infinite_rh(
+        fov_y: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        depth: ClipDepth,
+    ) -> Self {
+        let (sin, cos) = (fov_y.into() * T::one_half()).sin_cos();
+        let focal = cos / sin;
+        let m23 = match depth {
+            ClipDepth::ZeroToOne => T::zero() - near,
+            ClipDepth::NegOneToOne => T::zero() - (near + near),
+        };
+
+        Self {
+            m00: focal / aspect,
+            m11: focal,
+            m22: T::neg_one(),
+            m23,
+            m32: T::neg_one(),
+            ..MatrixOps::zeroed()
+        }
+    }
+
+    pub fn perspective_infinite_lh(
+        fov_y: impl Into<Rad<T>>,
+        aspect: T,
+        near: T,
+        depth: ClipDepth,
+    ) -> Self {
+        Self::perspective_infinite_rh(fov_y, aspect, near, depth).flip_z()
+    }
+
+    // Reverse-Z maps the near plane to 1 and the far plane to 0. It is only meaningful for a
+    // ZeroToOne clip range, so there is no depth parameter.
+    pub fn perspective_reverse_z_rh(fov_y: impl Into<Rad<T>>, aspect: T, near: T, far: T) -> Self {
+        let (sin, cos) = (fov_y.into() * T::one_half()).sin_cos();
+        let focal = cos / sin;
+
+        Self {
+            m00: focal / aspect,
+            m11: focal,
+            m22: near / (far - near),
+            m23: near * far / (far - near),
+            m32: T::neg_one(),
+            ..MatrixOps::zeroed()
+        }
+    }
+
+    pub fn perspective_reverse_z_lh(fov_y: impl Into<Rad<T>>, aspect: T, near: T, far: T) -> Self {
+        Self::perspective_reverse_z_rh(fov_y, aspect, near, far).flip_z()
+    }
+
+    pub fn perspective_infinite_reverse_z_rh(fov_y: impl Into<Rad<T>>, aspect: T, near: T) -> Self {
+        let (sin, cos) = (fov_y.into() * T::one_half()).sin_cos();
+        let focal = cos / sin;
+
+        Self {
+            m00: focal / aspect,
+            m11: focal,
+            m23: near,
+            m32: T::neg_one(),
+            ..MatrixOps::zeroed()
+        }
+    }
+
+    pub fn perspective_infinite_reverse_z_lh(fov_y: impl Into<Rad<T>>, aspect: T, near: T) -> Self {
+        Self::perspective_infinite_reverse_z_rh(fov_y, aspect, near).flip_z()
+    }
+
+    pub fn orthographic_rh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth: ClipDepth,
+    ) -> Self {
+        let two = T::two();
+        let (m22, m23) = match depth {
+            ClipDepth::ZeroToOne => (T::one() / (near - far), near / (near - far)),
+            ClipDepth::NegOneToOne => (two / (near - far), (far + near) / (near - far)),
+        };
+
+        Self {
+            m00: two / (right - left),
+            m03: (right + left) / (left - right),
+            m11: two / (top - bottom),
+            m13: (top + bottom) / (bottom - top),
+            m22,
+            m23,
+            m33: T::one(),
+            ..MatrixOps::zeroed()
+        }
+    }
+
+    pub fn orthographic_lh(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        depth: ClipDepth,
+    ) -> Self {
+        Self::orthographic_rh(left, right, bottom, top, near, far, depth).flip_z()
+    }
+
+    // View matrices are the inverse of the camera's rigid transform. The right handed ones look
+    // down -Z in view space, the left handed ones down +Z. None when there is no direction to look
+    // in. When `up` is parallel to the direction an arbitrary perpendicular up is used, as in
+    // Quaternion::look_rotation.
+    pub fn look_to_rh(eye: Vec3<T>, direction: Vec3<T>, up: Vec3<T>) -> Option<Self> {
+        let forward = direction.try_normalize()?;
+        let right = Self::view_right(forward, up);
+        let up = right.cross(forward);
+
+        Some(Self::from_view_basis(eye, -forward, right, up))
+    }
+
+    pub fn look_to_lh(eye: Vec3<T>, direction: Vec3<T>, up: Vec3<T>) -> Option<Self> {
+        let forward = direction.try_normalize()?;
+        let right = -Self::view_right(forward, up);
+        let up = forward.cross(right);
+
+        Some(Self::from_view_basis(eye, forward, right, up))
+    }
+
+    pub fn look_at_rh(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Option<Self> {
+        Self::look_to_rh(eye, target - eye, up)
+    }
+
+    pub fn look_at_lh(eye: Vec3<T>, target: Vec3<T>, up: Vec3<T>) -> Option<Self> {
+        Self::look_to_lh(eye, target - eye, up)
+    }
+
+    // Unit forward x up, for a unit forward.
+    fn view_right(forward: Vec3<T>, up: Vec3<T>) -> Vec3<T> {
+        let right = forward.cross(up);
+        let length = right.magnitude();
+        if length > T::epsilon().sqrt() * up.magnitude() {
+            return right * (T::one() / length);
+        }
+
+        let helper = if forward.x.abs() < T::one_half() {
+            Vec3::unit_x()
+        } else {
+            Vec3::unit_y()
+        };
+        forward.cross(helper).normalize()
+    }
+
+    fn from_view_basis(eye: Vec3<T>, z: Vec3<T>, x: Vec3<T>, y: Vec3<T>) -> Self {
+        Self {
+            m00: x.x,
+            m01: x.y,
+            m02: x.z,
+            m03: T::zero() - x.dot(eye),
+            m10: y.x,
+            m11: y.y,
+            m12: y.z,
+            m13: T::zero() - y.dot(eye),
+            m20: z.x,
+            m21: z.y,
+            m22: z.z,
+            m23: T::zero() - z.dot(eye),
+            m33: T::one(),
+            ..MatrixOps::zeroed()
+        }
+    }
+
+    // Returns (eye, forward, right, up) in world space for a right handed view matrix.
+    pub fn view_basis_rh(self) -> (Vec3<T>, Vec3<T>, Vec3<T>, Vec3<T>) {
+        let (eye, z, right, up) = self.view_axes();
+        (eye, z * T::neg_one(), right, up)
+    }
+
+    // Returns (eye, forward, right, up) in world space for a left handed view matrix.
+    pub fn view_basis_lh(self) -> (Vec3<T>, Vec3<T>, Vec3<T>, Vec3<T>) {
+        self.view_axes()
+    }
+
+    fn view_axes(self) -> (Vec3<T>, Vec3<T>, Vec3<T>, Vec3<T>) {
+        let x = Vec3::new(self.m00, self.m01, self.m02);
+        let y = Vec3::new(self.m10, self.m11, self.m12);
+        let z = Vec3::new(self.m20, self.m21, self.m22);
+        let eye = (x * self.m03 + y * self.m13 + z * self.m23) * T::neg_one();
+
+        (eye, z, x, y)
+    }
+}
+
+impl<T> Add for Mat4<T>
+where
+    T: Number,
+    T: Add<Output = T>,
 {
     type Output = Mat4<T>;
 
@@ -702,7 +1873,7 @@ where
 
 impl<T> Sub for Mat4<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
 {
     type Output = Mat4<T>;
@@ -731,7 +1902,7 @@ where
 
 impl<T> Mul<T> for Mat4<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -761,7 +1932,7 @@ where
 
 impl<T> Mul for Mat4<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Add<Output = T>,
     T: Copy,
@@ -792,7 +1963,7 @@ where
 
 impl<T> Mul<Vec4<T>> for Mat4<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Add<Output = T>,
     T: Copy,
@@ -809,9 +1980,28 @@ where
     }
 }
 
+impl<T> Mul<Mat4<T>> for Vec4<T>
+where
+    T: Number,
+    T: Mul<Output = T>,
+    T: Add<Output = T>,
+    T: Copy,
+{
+    type Output = Vec4<T>;
+
+    fn mul(self, rhs: Mat4<T>) -> Self::Output {
+        Self::Output {
+            x: self.x * rhs.m00 + self.y * rhs.m10 + self.z * rhs.m20 + self.w * rhs.m30,
+            y: self.x * rhs.m01 + self.y * rhs.m11 + self.z * rhs.m21 + self.w * rhs.m31,
+            z: self.x * rhs.m02 + self.y * rhs.m12 + self.z * rhs.m22 + self.w * rhs.m32,
+            w: self.x * rhs.m03 + self.y * rhs.m13 + self.z * rhs.m23 + self.w * rhs.m33,
+        }
+    }
+}
+
 impl<T> AsRef<[T; 16]> for Mat4<T>
 where
-    T: Float,
+    T: Number,
 {
     fn as_ref(&self) -> &[T; 16] {
         unsafe { std::mem::transmute(self) }
@@ -824,7 +2014,7 @@ where
 
 impl<T> From<[[T; 2]; 2]> for Mat2<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -840,7 +2030,7 @@ where
 
 impl<T> From<[T; 4]> for Mat2<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -860,7 +2050,7 @@ where
 
 impl<T> From<[[T; 3]; 3]> for Mat3<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -881,7 +2071,7 @@ where
 
 impl<T> From<[T; 9]> for Mat3<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -906,7 +2096,7 @@ where
 
 impl<T> From<[[T; 4]; 4]> for Mat4<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -934,7 +2124,7 @@ where
 
 impl<T> From<[T; 16]> for Mat4<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
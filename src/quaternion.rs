@@ -1,9 +1,12 @@
-use std::cmp::PartialEq;
+use std::cmp::{Ordering, PartialEq};
 use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign};
 
-use crate::VectorOps;
+use crate::{CrossOps, FloatVectorOps, VectorOps};
 
+use super::angle::{Deg, Rad};
 use super::base::Float;
+use super::matrix::Mat3;
+use super::unit::Unit;
 use super::vector::Vec3;
 
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
@@ -12,6 +15,43 @@ pub struct Quaternion<T: Float> {
     pub vector: Vec3<T>,
 }
 
+// Rotation order for Euler angles. Rotations are intrinsic: XYZ rotates about X, then about the
+// rotated Y, then about the twice rotated Z, which is the matrix product X * Y * Z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerRot {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+impl EulerRot {
+    fn axes(self) -> [usize; 3] {
+        match self {
+            EulerRot::XYZ => [0, 1, 2],
+            EulerRot::XZY => [0, 2, 1],
+            EulerRot::YXZ => [1, 0, 2],
+            EulerRot::YZX => [1, 2, 0],
+            EulerRot::ZXY => [2, 0, 1],
+            EulerRot::ZYX => [2, 1, 0],
+            EulerRot::XYX => [0, 1, 0],
+            EulerRot::XZX => [0, 2, 0],
+            EulerRot::YXY => [1, 0, 1],
+            EulerRot::YZY => [1, 2, 1],
+            EulerRot::ZXZ => [2, 0, 2],
+            EulerRot::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
 impl<T> Quaternion<T>
 where
     T: Float,
@@ -19,11 +59,221 @@ where
     T: Sub<Output = T>,
     T: Mul<Output = T>,
     T: Div<Output = T>,
+    T: PartialOrd,
 {
     pub fn new(scalar: T, vector: Vec3<T>) -> Self {
         Self { scalar, vector }
     }
 
+    pub fn identity() -> Self {
+        Self::new(T::one(), Vec3::new(T::zero(), T::zero(), T::zero()))
+    }
+
+    // The rotation constructors build plain quaternions for arithmetic. Unit<Quaternion> has the
+    // same constructors for the APIs that take a rotation.
+    pub fn from_axis_angle(axis: Unit<Vec3<T>>, angle: impl Into<Rad<T>>) -> Self {
+        let (sin, cos) = (angle.into() * T::one_half()).sin_cos();
+        Self::new(cos, *axis * sin)
+    }
+
+    // Returns the rotation axis and the angle, within [0, 2 * PI]. The identity has no meaningful
+    // axis and reports unit x.
+    pub fn to_axis_angle(&self) -> (Unit<Vec3<T>>, Rad<T>) {
+        let mut q = *self;
+        q.normalize();
+
+        let two = T::two();
+        let angle = two * q.scalar.clamp(T::neg_one(), T::one()).acos();
+        let sin = q.vector.magnitude();
+        if sin <= T::epsilon() {
+            return (Vec3::x_axis(), Rad(angle));
+        }
+
+        (Unit::new_unchecked(q.vector * (T::one() / sin)), Rad(angle))
+    }
+
+    // Shortest rotation taking the direction `from` onto `to`. Opposite directions have no unique
+    // answer, so a half turn about an arbitrary perpendicular axis is used.
+    pub fn from_rotation_arc(from: Vec3<T>, to: Vec3<T>) -> Self {
+        let from = from.normalize();
+        let to = to.normalize();
+        let dot = from.dot(to);
+
+        if dot < T::epsilon().sqrt() - T::one() {
+            let helper = if from.x.abs() < T::one_half() {
+                Vec3::unit_x()
+            } else {
+                Vec3::unit_y()
+            };
+            return Self::new(T::zero(), from.cross(helper).normalize());
+        }
+
+        let mut q = Self::new(T::one() + dot, from.cross(to));
+        q.normalize();
+        q
+    }
+
+    // Rotation taking +Z onto `forward` and +Y as close to `up` as possible. When `up` is
+    // parallel to `forward` an arbitrary perpendicular up is used.
+    pub fn look_rotation(forward: Vec3<T>, up: Vec3<T>) -> Self {
+        let z = forward.normalize();
+        let mut x = up.cross(z);
+        if x.magnitude() <= T::epsilon().sqrt() {
+            let helper = if z.x.abs() < T::one_half() {
+                Vec3::unit_x()
+            } else {
+                Vec3::unit_y()
+            };
+            x = helper.cross(z);
+        }
+        let x = x.normalize();
+        let y = z.cross(x);
+
+        Self::from_rotation_matrix(Mat3 {
+            m00: x.x,
+            m01: y.x,
+            m02: z.x,
+            m10: x.y,
+            m11: y.y,
+            m12: z.y,
+            m20: x.z,
+            m21: y.z,
+            m22: z.z,
+        })
+    }
+
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<Rad<T>>,
+        b: impl Into<Rad<T>>,
+        c: impl Into<Rad<T>>,
+    ) -> Self {
+        let axis = |index: usize| {
+            let mut axis = [T::zero(), T::zero(), T::zero()];
+            axis[index] = T::one();
+            Unit::new_unchecked(Vec3::new(axis[0], axis[1], axis[2]))
+        };
+        let [first, second, third] = order.axes();
+
+        Self::from_axis_angle(axis(first), a)
+            * Self::from_axis_angle(axis(second), b)
+            * Self::from_axis_angle(axis(third), c)
+    }
+
+    // Returns the angles (a, b, c) such that from_euler(order, a, b, c) gives this rotation. In
+    // gimbal lock only the sum or difference of a and c is defined, so c is reported as zero.
+    //
+    // Bernardes and Viollet, "Quaternion to Euler angles conversion: A direct, general and
+    // computationally efficient method", 2022. The method works on extrinsic sequences, and an
+    // intrinsic sequence is the extrinsic one with the axes and angles reversed.
+    pub fn to_euler(&self, order: EulerRot) -> (Rad<T>, Rad<T>, Rad<T>) {
+        let mut q = *self;
+        q.normalize();
+
+        let two = T::two();
+        let pi = T::pi();
+        let [third, second, first] = order.axes();
+        let (i, j) = (first, second);
+        let proper = first == third;
+        let k = if proper { 3 - i - j } else { third };
+        let parity = if (i + 1) % 3 == j {
+            T::one()
+        } else {
+            T::neg_one()
+        };
+
+        let w = q.scalar;
+        let v = [q.vector.x, q.vector.y, q.vector.z];
+        let (a, b, c, d) = if proper {
+            (w, v[i], v[j], v[k] * parity)
+        } else {
+            (
+                w - v[j],
+                v[i] + v[k] * parity,
+                v[j] + w,
+                v[k] * parity - v[i],
+            )
+        };
+
+        let mut theta2 = two * (c * c + d * d).sqrt().atan2((a * a + b * b).sqrt());
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+
+        let lock = T::epsilon().sqrt();
+        let (mut theta1, mut theta3) = if theta2.abs() <= lock {
+            (T::zero(), two * half_sum)
+        } else if (theta2 - pi).abs() <= lock {
+            (T::zero(), two * half_diff)
+        } else {
+            (half_sum - half_diff, half_sum + half_diff)
+        };
+
+        if !proper {
+            theta3 *= parity;
+            theta2 -= T::frac_pi_2();
+        }
+
+        let wrap = |angle: T| {
+            if angle > pi {
+                angle - T::tau()
+            } else if angle < T::zero() - pi {
+                angle + T::tau()
+            } else {
+                angle
+            }
+        };
+        theta1 = wrap(theta1);
+        theta3 = wrap(theta3);
+
+        (Rad(theta3), Rad(theta2), Rad(theta1))
+    }
+
+    // Shepperd's method: solve for the largest quaternion component first so the division below
+    // never goes through a value close to zero.
+    pub fn from_rotation_matrix(m: Mat3<T>) -> Self {
+        let two = T::two();
+        let quarter = T::one_half() * T::one_half();
+        let trace = m.m00 + m.m11 + m.m22;
+
+        let (w, x, y, z) = if trace > T::zero() {
+            let s = (trace + T::one()).sqrt() * two;
+            (
+                s * quarter,
+                (m.m21 - m.m12) / s,
+                (m.m02 - m.m20) / s,
+                (m.m10 - m.m01) / s,
+            )
+        } else if m.m00 > m.m11 && m.m00 > m.m22 {
+            let s = (T::one() + m.m00 - m.m11 - m.m22).sqrt() * two;
+            (
+                (m.m21 - m.m12) / s,
+                s * quarter,
+                (m.m01 + m.m10) / s,
+                (m.m02 + m.m20) / s,
+            )
+        } else if m.m11 > m.m22 {
+            let s = (T::one() + m.m11 - m.m00 - m.m22).sqrt() * two;
+            (
+                (m.m02 - m.m20) / s,
+                (m.m01 + m.m10) / s,
+                s * quarter,
+                (m.m12 + m.m21) / s,
+            )
+        } else {
+            let s = (T::one() + m.m22 - m.m00 - m.m11).sqrt() * two;
+            (
+                (m.m10 - m.m01) / s,
+                (m.m02 + m.m20) / s,
+                (m.m12 + m.m21) / s,
+                s * quarter,
+            )
+        };
+
+        let mut q = Self::new(w, Vec3::new(x, y, z));
+        q.normalize();
+        q
+    }
+
     pub fn product(&self, q: &Quaternion<T>) -> Self {
         let scalar = self.scalar * q.scalar - self.vector.dot(q.vector);
         let imaginary =
@@ -52,12 +302,12 @@ where
         }
     }
 
+    // Leaves a quaternion without an axis unchanged.
+    #[deprecated(note = "use Quaternion::from_axis_angle")]
     pub fn unit_norm(&mut self) {
-        let angle = T::to_radians(self.scalar);
-        self.vector = self.vector.normalize();
-
-        self.scalar = T::cos(angle * T::one_half());
-        self.vector *= T::sin(angle * T::one_half());
+        if let Some(axis) = Unit::new_normalize(self.vector) {
+            *self = Self::from_axis_angle(axis, Deg(self.scalar));
+        }
     }
 
     pub fn conjugate(&self) -> Self {
@@ -85,29 +335,289 @@ where
             vector: imaginary,
         }
     }
+
+    // Splits the rotation into (swing, twist) with self = swing * twist, where twist rotates about
+    // `axis` and swing rotates about an axis perpendicular to it.
+    pub fn swing_twist(&self, axis: Unit<Vec3<T>>) -> (Self, Self) {
+        let axis = axis.into_inner();
+        let projected = axis * self.vector.dot(axis);
+
+        let mut twist = Self::new(self.scalar, projected);
+        if twist.magnitude() <= T::epsilon() {
+            // Swing is a half turn, so the twist is undefined.
+            twist = Self::identity();
+        }
+        twist.normalize();
+
+        (*self * twist.conjugate(), twist)
+    }
+
+    // Same unit length requirement as Mul<Vec3>.
+    pub fn rotate_slice(&self, vectors: &mut [Vec3<T>]) {
+        for vector in vectors.iter_mut() {
+            *vector = *self * *vector;
+        }
+    }
+
+    pub fn average(rotations: &[Quaternion<T>]) -> Option<Self> {
+        let weights: Vec<T> = rotations.iter().map(|_| T::one()).collect();
+        Self::weighted_average(rotations, &weights)
+    }
+
+    // Markley et al., "Averaging Quaternions", 2007. The mean rotation is the eigenvector with the
+    // largest eigenvalue of the weighted sum of q * q^T, which is insensitive to the sign of each
+    // input. None for bad weights, as nlerp_average, or when the two largest eigenvalues are too
+    // close for the mean to be well defined.
+    pub fn weighted_average(rotations: &[Quaternion<T>], weights: &[T]) -> Option<Self> {
+        // Only used to validate the weights and to pick the sign of the result.
+        let seed = Self::nlerp_average(rotations, weights)?;
+
+        let mut accumulator = [[T::zero(); 4]; 4];
+        for (q, weight) in rotations.iter().zip(weights) {
+            let v = [q.vector.x, q.vector.y, q.vector.z, q.scalar];
+            for row in 0..4 {
+                for col in 0..4 {
+                    accumulator[row][col] += *weight * v[row] * v[col];
+                }
+            }
+        }
+
+        let (values, vectors) = symmetric_eigen(accumulator);
+        let mut order = [0, 1, 2, 3];
+        order.sort_by(|a, b| {
+            values[*b]
+                .partial_cmp(&values[*a])
+                .unwrap_or(Ordering::Equal)
+        });
+        let (largest, second) = (values[order[0]], values[order[1]]);
+        if largest - second <= T::epsilon().sqrt() * largest {
+            return None;
+        }
+
+        let v = |row: usize| vectors[row][order[0]];
+        let mut res = Self::new(v(3), Vec3::new(v(0), v(1), v(2)));
+        if res.dot(&seed) < T::zero() {
+            res = res * T::neg_one();
+        }
+        res.normalize();
+        Some(res)
+    }
+
+    // Weighted sum of the inputs, each flipped into the hemisphere of the first, then normalized.
+    // Cheap and close to the true mean when the rotations are tightly clustered. None for empty
+    // input, a weight count that does not match, negative weights or weights that sum to zero.
+    pub fn nlerp_average(rotations: &[Quaternion<T>], weights: &[T]) -> Option<Self> {
+        let total = weights
+            .iter()
+            .fold(T::zero(), |total, weight| total + *weight);
+        let negative = weights.iter().any(|weight| *weight < T::zero());
+        if rotations.len() != weights.len() || negative || total == T::zero() {
+            return None;
+        }
+
+        let first = rotations.first()?;
+        let mut sum = Self::new(T::zero(), Vec3::new(T::zero(), T::zero(), T::zero()));
+        for (q, weight) in rotations.iter().zip(weights) {
+            let (aligned, _) = first.shortest_path(q);
+            sum += aligned * *weight;
+        }
+
+        if sum.magnitude() <= T::epsilon() {
+            return None;
+        }
+        sum.normalize();
+        Some(sum)
+    }
+
+    pub fn exp(&self) -> Self {
+        let angle = self.vector.magnitude();
+        let scale = self.scalar.exp();
+        if angle <= T::epsilon() {
+            return Self::new(scale, self.vector * scale);
+        }
+
+        let (sin, cos) = angle.sin_cos();
+        Self::new(scale * cos, self.vector * (scale * sin / angle))
+    }
+
+    pub fn ln(&self) -> Self {
+        let magnitude = self.magnitude();
+        let sin = self.vector.magnitude();
+        let angle = sin.atan2(self.scalar);
+        if sin <= T::zero() {
+            // A real quaternion has no axis of its own. The angle is zero for a positive scalar and
+            // pi for a negative one, about any axis.
+            return Self::new(magnitude.ln(), Vec3::unit_x() * angle);
+        }
+
+        Self::new(magnitude.ln(), self.vector * (angle / sin))
+    }
+
+    // For a unit quaternion this scales the rotation angle by `exponent`.
+    pub fn powf(&self, exponent: T) -> Self {
+        (self.ln() * exponent).exp()
+    }
+
+    // Advances the orientation by an angular velocity given in world space, in radians per unit
+    // of `dt`. The step is the exact exponential map, so a constant velocity integrates exactly.
+    pub fn integrate(&self, angular_velocity: Vec3<T>, dt: T) -> Self {
+        let step = Self::new(T::zero(), angular_velocity * (dt * T::one_half())).exp();
+        let mut res = step * *self;
+        res.normalize();
+        res
+    }
+
+    // As `integrate`, with the angular velocity given in the body's local frame.
+    pub fn integrate_body(&self, angular_velocity: Vec3<T>, dt: T) -> Self {
+        let step = Self::new(T::zero(), angular_velocity * (dt * T::one_half())).exp();
+        let mut res = *self * step;
+        res.normalize();
+        res
+    }
+
+    pub fn dot(&self, q: &Quaternion<T>) -> T {
+        self.scalar * q.scalar + self.vector.dot(q.vector)
+    }
+
+    // q and -q are the same rotation. Flip `other` into the hemisphere of `self` so blending takes
+    // the shorter way around.
+    fn shortest_path(&self, other: &Quaternion<T>) -> (Self, T) {
+        let dot = self.dot(other);
+        if dot < T::zero() {
+            (*other * T::neg_one(), T::zero() - dot)
+        } else {
+            (*other, dot)
+        }
+    }
+
+    pub fn nlerp(&self, other: &Quaternion<T>, frac: T) -> Self {
+        let (other, _) = self.shortest_path(other);
+        let mut res = *self + (other - *self) * frac;
+        res.normalize();
+        res
+    }
+
+    pub fn slerp(&self, other: &Quaternion<T>, frac: T) -> Self {
+        let (other, dot) = self.shortest_path(other);
+
+        // sin(theta) vanishes as the inputs converge, where nlerp is indistinguishable anyway.
+        if dot > T::one() - T::epsilon().sqrt() {
+            return self.nlerp(&other, frac);
+        }
+
+        let theta = dot.acos();
+        let inv_sin = T::one() / theta.sin();
+        let from = ((T::one() - frac) * theta).sin() * inv_sin;
+        let to = (frac * theta).sin() * inv_sin;
+
+        *self * from + other * to
+    }
+
+    // Spherical cubic interpolation from `self` to `other`, shaped by the inner control points
+    // `a` and `b`. See `squad_control_point` for deriving those from neighbouring keyframes.
+    pub fn squad(
+        &self,
+        a: &Quaternion<T>,
+        b: &Quaternion<T>,
+        other: &Quaternion<T>,
+        frac: T,
+    ) -> Self {
+        let two = T::two();
+        let outer = self.slerp(other, frac);
+        let inner = a.slerp(b, frac);
+
+        outer.slerp(&inner, two * frac * (T::one() - frac))
+    }
+
+    // Control point for the keyframe `current`, given the keyframes either side of it.
+    pub fn squad_control_point(
+        prev: &Quaternion<T>,
+        current: &Quaternion<T>,
+        next: &Quaternion<T>,
+    ) -> Self {
+        let (prev, _) = current.shortest_path(prev);
+        let (next, _) = current.shortest_path(next);
+        let inverse = current.conjugate();
+
+        let sum = (inverse * next).ln() + (inverse * prev).ln();
+        let quarter = T::one_half() * T::one_half();
+
+        *current * (sum * (T::zero() - quarter)).exp()
+    }
 }
 
-pub fn rotate_on_axis<T>(starting_vector: Vec3<T>, angle: T, axis: Vec3<T>) -> Vec3<T>
+pub fn rotate_on_axis<T>(
+    starting_vector: Vec3<T>,
+    angle: impl Into<Rad<T>>,
+    axis: Unit<Vec3<T>>,
+) -> Vec3<T>
 where
     T: Float,
     T: Add<Output = T>,
     T: Sub<Output = T>,
     T: Mul<Output = T>,
     T: Div<Output = T>,
+    T: PartialOrd,
 {
-    let pure_quaternion = Quaternion::new(T::zero(), starting_vector);
-
-    let axis = axis.normalize();
-
-    let mut real_quaternion = Quaternion::new(angle, axis);
+    Quaternion::from_axis_angle(axis, angle) * starting_vector
+}
 
-    real_quaternion.unit_norm();
+// Cyclic Jacobi eigenvalue algorithm for a symmetric 4x4 matrix. Returns the eigenvalues and the
+// matching unit eigenvectors as the columns of the second array.
+fn symmetric_eigen<T: Float>(mut a: [[T; 4]; 4]) -> ([T; 4], [[T; 4]; 4]) {
+    let mut v = [[T::zero(); 4]; 4];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = T::one();
+    }
 
-    let inverse = real_quaternion.inverse();
+    for _ in 0..50 {
+        let mut off = T::zero();
+        let mut total = T::zero();
+        for (p, row) in a.iter().enumerate() {
+            for (q, element) in row.iter().enumerate() {
+                if p != q {
+                    off += *element * *element;
+                }
+                total += *element * *element;
+            }
+        }
+        if off <= T::epsilon() * T::epsilon() * total {
+            break;
+        }
 
-    let rotated_vector = real_quaternion * pure_quaternion * inverse;
+        for p in 0..3 {
+            for q in (p + 1)..4 {
+                if a[p][q] == T::zero() {
+                    continue;
+                }
+
+                // Rotation in the pq plane that zeroes a[p][q], taking the smaller angle.
+                let theta = (a[q][q] - a[p][p]) / (T::two() * a[p][q]);
+                let t = T::one().copysign(theta) / (theta.abs() + theta.hypot(T::one()));
+                let c = T::one() / t.hypot(T::one());
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (head, tail) = a.split_at_mut(q);
+                for (pk, qk) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                    let (kp, kq) = (*pk, *qk);
+                    *pk = c * kp - s * kq;
+                    *qk = s * kp + c * kq;
+                }
+                for row in v.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+            }
+        }
+    }
 
-    rotated_vector.vector
+    ([a[0][0], a[1][1], a[2][2], a[3][3]], v)
 }
 
 impl<T> Add for Quaternion<T>
@@ -204,6 +714,29 @@ where
     }
 }
 
+// Rotates the vector by a unit quaternion. Equivalent to the sandwich product q * v * q^-1 but
+// expanded into two cross products, which assumes unit length: any other quaternion also scales
+// and skews the result. Normalize first, or multiply by a Unit<Quaternion>.
+impl<T> Mul<Vec3<T>> for Quaternion<T>
+where
+    T: Float,
+    T: Add<Output = T>,
+    T: Sub<Output = T>,
+    T: Div<Output = T>,
+    T: Mul<Output = T>,
+{
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: Vec3<T>) -> Self::Output {
+        debug_assert!(
+            (self.dot(&self) - T::one()).abs() <= T::epsilon().sqrt(),
+            "only a unit quaternion rotates a vector"
+        );
+        let t = self.vector.cross(rhs) * T::two();
+        rhs + t * self.scalar + self.vector.cross(t)
+    }
+}
+
 impl<T> MulAssign for Quaternion<T>
 where
     T: Float,
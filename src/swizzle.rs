@@ -0,0 +1,260 @@
+use super::base::Number;
+use super::vector::{Vec2, Vec3, Vec4};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Macros
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Each entry is `name: components`, building the output vector from the listed components in
+// order.
+macro_rules! swizzles {
+    ($vec:ident => $out:ident { $($name:ident: $($component:ident)+),+ $(,)? }) => {
+        impl<T: Number> $vec<T> {
+            $(
+                pub fn $name(self) -> $out<T> {
+                    $out::new($(self.$component),+)
+                }
+            )+
+        }
+    };
+}
+
+// Each entry is `name: target = source, ...`, writing the source components of the value into
+// the target components of self. Only combinations without repeats can be assigned.
+macro_rules! swizzle_setters {
+    ($vec:ident <= $in:ident { $($name:ident: $($target:ident = $source:ident)+),+ $(,)? }) => {
+        impl<T: Number> $vec<T> {
+            $(
+                pub fn $name(&mut self, value: $in<T>) {
+                    $(self.$target = value.$source;)+
+                }
+            )+
+        }
+    };
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Vec2
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[rustfmt::skip]
+swizzles!(Vec2 => Vec2 {
+    xx: x x, xy: x y, yx: y x, yy: y y,
+});
+
+#[rustfmt::skip]
+swizzles!(Vec2 => Vec3 {
+    xxx: x x x, xxy: x x y, xyx: x y x, xyy: x y y, yxx: y x x, yxy: y x y, yyx: y y x,
+    yyy: y y y,
+});
+
+#[rustfmt::skip]
+swizzles!(Vec2 => Vec4 {
+    xxxx: x x x x, xxxy: x x x y, xxyx: x x y x, xxyy: x x y y, xyxx: x y x x, xyxy: x y x y,
+    xyyx: x y y x, xyyy: x y y y, yxxx: y x x x, yxxy: y x x y, yxyx: y x y x, yxyy: y x y y,
+    yyxx: y y x x, yyxy: y y x y, yyyx: y y y x, yyyy: y y y y,
+});
+
+#[rustfmt::skip]
+swizzle_setters!(Vec2 <= Vec2 {
+    set_xy: x = x y = y, set_yx: y = x x = y,
+});
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Vec3
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[rustfmt::skip]
+swizzles!(Vec3 => Vec2 {
+    xx: x x, xy: x y, xz: x z, yx: y x, yy: y y, yz: y z, zx: z x, zy: z y, zz: z z,
+});
+
+#[rustfmt::skip]
+swizzles!(Vec3 => Vec3 {
+    xxx: x x x, xxy: x x y, xxz: x x z, xyx: x y x, xyy: x y y, xyz: x y z, xzx: x z x,
+    xzy: x z y, xzz: x z z, yxx: y x x, yxy: y x y, yxz: y x z, yyx: y y x, yyy: y y y,
+    yyz: y y z, yzx: y z x, yzy: y z y, yzz: y z z, zxx: z x x, zxy: z x y, zxz: z x z,
+    zyx: z y x, zyy: z y y, zyz: z y z, zzx: z z x, zzy: z z y, zzz: z z z,
+});
+
+#[rustfmt::skip]
+swizzles!(Vec3 => Vec4 {
+    xxxx: x x x x, xxxy: x x x y, xxxz: x x x z, xxyx: x x y x, xxyy: x x y y, xxyz: x x y z,
+    xxzx: x x z x, xxzy: x x z y, xxzz: x x z z, xyxx: x y x x, xyxy: x y x y, xyxz: x y x z,
+    xyyx: x y y x, xyyy: x y y y, xyyz: x y y z, xyzx: x y z x, xyzy: x y z y, xyzz: x y z z,
+    xzxx: x z x x, xzxy: x z x y, xzxz: x z x z, xzyx: x z y x, xzyy: x z y y, xzyz: x z y z,
+    xzzx: x z z x, xzzy: x z z y, xzzz: x z z z, yxxx: y x x x, yxxy: y x x y, yxxz: y x x z,
+    yxyx: y x y x, yxyy: y x y y, yxyz: y x y z, yxzx: y x z x, yxzy: y x z y, yxzz: y x z z,
+    yyxx: y y x x, yyxy: y y x y, yyxz: y y x z, yyyx: y y y x, yyyy: y y y y, yyyz: y y y z,
+    yyzx: y y z x, yyzy: y y z y, yyzz: y y z z, yzxx: y z x x, yzxy: y z x y, yzxz: y z x z,
+    yzyx: y z y x, yzyy: y z y y, yzyz: y z y z, yzzx: y z z x, yzzy: y z z y, yzzz: y z z z,
+    zxxx: z x x x, zxxy: z x x y, zxxz: z x x z, zxyx: z x y x, zxyy: z x y y, zxyz: z x y z,
+    zxzx: z x z x, zxzy: z x z y, zxzz: z x z z, zyxx: z y x x, zyxy: z y x y, zyxz: z y x z,
+    zyyx: z y y x, zyyy: z y y y, zyyz: z y y z, zyzx: z y z x, zyzy: z y z y, zyzz: z y z z,
+    zzxx: z z x x, zzxy: z z x y, zzxz: z z x z, zzyx: z z y x, zzyy: z z y y, zzyz: z z y z,
+    zzzx: z z z x, zzzy: z z z y, zzzz: z z z z,
+});
+
+#[rustfmt::skip]
+swizzle_setters!(Vec3 <= Vec2 {
+    set_xy: x = x y = y, set_xz: x = x z = y, set_yx: y = x x = y, set_yz: y = x z = y,
+    set_zx: z = x x = y, set_zy: z = x y = y,
+});
+
+#[rustfmt::skip]
+swizzle_setters!(Vec3 <= Vec3 {
+    set_xyz: x = x y = y z = z, set_xzy: x = x z = y y = z, set_yxz: y = x x = y z = z,
+    set_yzx: y = x z = y x = z, set_zxy: z = x x = y y = z, set_zyx: z = x y = y x = z,
+});
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Vec4
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[rustfmt::skip]
+swizzles!(Vec4 => Vec2 {
+    xx: x x, xy: x y, xz: x z, xw: x w, yx: y x, yy: y y, yz: y z, yw: y w, zx: z x, zy: z y,
+    zz: z z, zw: z w, wx: w x, wy: w y, wz: w z, ww: w w,
+});
+
+#[rustfmt::skip]
+swizzles!(Vec4 => Vec3 {
+    xxx: x x x, xxy: x x y, xxz: x x z, xxw: x x w, xyx: x y x, xyy: x y y, xyz: x y z,
+    xyw: x y w, xzx: x z x, xzy: x z y, xzz: x z z, xzw: x z w, xwx: x w x, xwy: x w y,
+    xwz: x w z, xww: x w w, yxx: y x x, yxy: y x y, yxz: y x z, yxw: y x w, yyx: y y x,
+    yyy: y y y, yyz: y y z, yyw: y y w, yzx: y z x, yzy: y z y, yzz: y z z, yzw: y z w,
+    ywx: y w x, ywy: y w y, ywz: y w z, yww: y w w, zxx: z x x, zxy: z x y, zxz: z x z,
+    zxw: z x w, zyx: z y x, zyy: z y y, zyz: z y z, zyw: z y w, zzx: z z x, zzy: z z y,
+    zzz: z z z, zzw: z z w, zwx: z w x, zwy: z w y, zwz: z w z, zww: z w w, wxx: w x x,
+    wxy: w x y, wxz: w x z, wxw: w x w, wyx: w y x, wyy: w y y, wyz: w y z, wyw: w y w,
+    wzx: w z x, wzy: w z y, wzz: w z z, wzw: w z w, wwx: w w x, wwy: w w y, wwz: w w z,
+    www: w w w,
+});
+
+#[rustfmt::skip]
+swizzles!(Vec4 => Vec4 {
+    xxxx: x x x x, xxxy: x x x y, xxxz: x x x z, xxxw: x x x w, xxyx: x x y x, xxyy: x x y y,
+    xxyz: x x y z, xxyw: x x y w, xxzx: x x z x, xxzy: x x z y, xxzz: x x z z, xxzw: x x z w,
+    xxwx: x x w x, xxwy: x x w y, xxwz: x x w z, xxww: x x w w, xyxx: x y x x, xyxy: x y x y,
+    xyxz: x y x z, xyxw: x y x w, xyyx: x y y x, xyyy: x y y y, xyyz: x y y z, xyyw: x y y w,
+    xyzx: x y z x, xyzy: x y z y, xyzz: x y z z, xyzw: x y z w, xywx: x y w x, xywy: x y w y,
+    xywz: x y w z, xyww: x y w w, xzxx: x z x x, xzxy: x z x y, xzxz: x z x z, xzxw: x z x w,
+    xzyx: x z y x, xzyy: x z y y, xzyz: x z y z, xzyw: x z y w, xzzx: x z z x, xzzy: x z z y,
+    xzzz: x z z z, xzzw: x z z w, xzwx: x z w x, xzwy: x z w y, xzwz: x z w z, xzww: x z w w,
+    xwxx: x w x x, xwxy: x w x y, xwxz: x w x z, xwxw: x w x w, xwyx: x w y x, xwyy: x w y y,
+    xwyz: x w y z, xwyw: x w y w, xwzx: x w z x, xwzy: x w z y, xwzz: x w z z, xwzw: x w z w,
+    xwwx: x w w x, xwwy: x w w y, xwwz: x w w z, xwww: x w w w, yxxx: y x x x, yxxy: y x x y,
+    yxxz: y x x z, yxxw: y x x w, yxyx: y x y x, yxyy: y x y y, yxyz: y x y z, yxyw: y x y w,
+    yxzx: y x z x, yxzy: y x z y, yxzz: y x z z, yxzw: y x z w, yxwx: y x w x, yxwy: y x w y,
+    yxwz: y x w z, yxww: y x w w, yyxx: y y x x, yyxy: y y x y, yyxz: y y x z, yyxw: y y x w,
+    yyyx: y y y x, yyyy: y y y y, yyyz: y y y z, yyyw: y y y w, yyzx: y y z x, yyzy: y y z y,
+    yyzz: y y z z, yyzw: y y z w, yywx: y y w x, yywy: y y w y, yywz: y y w z, yyww: y y w w,
+    yzxx: y z x x, yzxy: y z x y, yzxz: y z x z, yzxw: y z x w, yzyx: y z y x, yzyy: y z y y,
+    yzyz: y z y z, yzyw: y z y w, yzzx: y z z x, yzzy: y z z y, yzzz: y z z z, yzzw: y z z w,
+    yzwx: y z w x, yzwy: y z w y, yzwz: y z w z, yzww: y z w w, ywxx: y w x x, ywxy: y w x y,
+    ywxz: y w x z, ywxw: y w x w, ywyx: y w y x, ywyy: y w y y, ywyz: y w y z, ywyw: y w y w,
+    ywzx: y w z x, ywzy: y w z y, ywzz: y w z z, ywzw: y w z w, ywwx: y w w x, ywwy: y w w y,
+    ywwz: y w w z, ywww: y w w w, zxxx: z x x x, zxxy: z x x y, zxxz: z x x z, zxxw: z x x w,
+    zxyx: z x y x, zxyy: z x y y, zxyz: z x y z, zxyw: z x y w, zxzx: z x z x, zxzy: z x z y,
+    zxzz: z x z z, zxzw: z x z w, zxwx: z x w x, zxwy: z x w y, zxwz: z x w z, zxww: z x w w,
+    zyxx: z y x x, zyxy: z y x y, zyxz: z y x z, zyxw: z y x w, zyyx: z y y x, zyyy: z y y y,
+    zyyz: z y y z, zyyw: z y y w, zyzx: z y z x, zyzy: z y z y, zyzz: z y z z, zyzw: z y z w,
+    zywx: z y w x, zywy: z y w y, zywz: z y w z, zyww: z y w w, zzxx: z z x x, zzxy: z z x y,
+    zzxz: z z x z, zzxw: z z x w, zzyx: z z y x, zzyy: z z y y, zzyz: z z y z, zzyw: z z y w,
+    zzzx: z z z x, zzzy: z z z y, zzzz: z z z z, zzzw: z z z w, zzwx: z z w x, zzwy: z z w y,
+    zzwz: z z w z, zzww: z z w w, zwxx: z w x x, zwxy: z w x y, zwxz: z w x z, zwxw: z w x w,
+    zwyx: z w y x, zwyy: z w y y, zwyz: z w y z, zwyw: z w y w, zwzx: z w z x, zwzy: z w z y,
+    zwzz: z w z z, zwzw: z w z w, zwwx: z w w x, zwwy: z w w y, zwwz: z w w z, zwww: z w w w,
+    wxxx: w x x x, wxxy: w x x y, wxxz: w x x z, wxxw: w x x w, wxyx: w x y x, wxyy: w x y y,
+    wxyz: w x y z, wxyw: w x y w, wxzx: w x z x, wxzy: w x z y, wxzz: w x z z, wxzw: w x z w,
+    wxwx: w x w x, wxwy: w x w y, wxwz: w x w z, wxww: w x w w, wyxx: w y x x, wyxy: w y x y,
+    wyxz: w y x z, wyxw: w y x w, wyyx: w y y x, wyyy: w y y y, wyyz: w y y z, wyyw: w y y w,
+    wyzx: w y z x, wyzy: w y z y, wyzz: w y z z, wyzw: w y z w, wywx: w y w x, wywy: w y w y,
+    wywz: w y w z, wyww: w y w w, wzxx: w z x x, wzxy: w z x y, wzxz: w z x z, wzxw: w z x w,
+    wzyx: w z y x, wzyy: w z y y, wzyz: w z y z, wzyw: w z y w, wzzx: w z z x, wzzy: w z z y,
+    wzzz: w z z z, wzzw: w z z w, wzwx: w z w x, wzwy: w z w y, wzwz: w z w z, wzww: w z w w,
+    wwxx: w w x x, wwxy: w w x y, wwxz: w w x z, wwxw: w w x w, wwyx: w w y x, wwyy: w w y y,
+    wwyz: w w y z, wwyw: w w y w, wwzx: w w z x, wwzy: w w z y, wwzz: w w z z, wwzw: w w z w,
+    wwwx: w w w x, wwwy: w w w y, wwwz: w w w z, wwww: w w w w,
+});
+
+#[rustfmt::skip]
+swizzle_setters!(Vec4 <= Vec2 {
+    set_xy: x = x y = y, set_xz: x = x z = y, set_xw: x = x w = y, set_yx: y = x x = y,
+    set_yz: y = x z = y, set_yw: y = x w = y, set_zx: z = x x = y, set_zy: z = x y = y,
+    set_zw: z = x w = y, set_wx: w = x x = y, set_wy: w = x y = y, set_wz: w = x z = y,
+});
+
+#[rustfmt::skip]
+swizzle_setters!(Vec4 <= Vec3 {
+    set_xyz: x = x y = y z = z, set_xyw: x = x y = y w = z, set_xzy: x = x z = y y = z,
+    set_xzw: x = x z = y w = z, set_xwy: x = x w = y y = z, set_xwz: x = x w = y z = z,
+    set_yxz: y = x x = y z = z, set_yxw: y = x x = y w = z, set_yzx: y = x z = y x = z,
+    set_yzw: y = x z = y w = z, set_ywx: y = x w = y x = z, set_ywz: y = x w = y z = z,
+    set_zxy: z = x x = y y = z, set_zxw: z = x x = y w = z, set_zyx: z = x y = y x = z,
+    set_zyw: z = x y = y w = z, set_zwx: z = x w = y x = z, set_zwy: z = x w = y y = z,
+    set_wxy: w = x x = y y = z, set_wxz: w = x x = y z = z, set_wyx: w = x y = y x = z,
+    set_wyz: w = x y = y z = z, set_wzx: w = x z = y x = z, set_wzy: w = x z = y y = z,
+});
+
+#[rustfmt::skip]
+swizzle_setters!(Vec4 <= Vec4 {
+    set_xyzw: x = x y = y z = z w = w, set_xywz: x = x y = y w = z z = w,
+    set_xzyw: x = x z = y y = z w = w, set_xzwy: x = x z = y w = z y = w,
+    set_xwyz: x = x w = y y = z z = w, set_xwzy: x = x w = y z = z y = w,
+    set_yxzw: y = x x = y z = z w = w, set_yxwz: y = x x = y w = z z = w,
+    set_yzxw: y = x z = y x = z w = w, set_yzwx: y = x z = y w = z x = w,
+    set_ywxz: y = x w = y x = z z = w, set_ywzx: y = x w = y z = z x = w,
+    set_zxyw: z = x x = y y = z w = w, set_zxwy: z = x x = y w = z y = w,
+    set_zyxw: z = x y = y x = z w = w, set_zywx: z = x y = y w = z x = w,
+    set_zwxy: z = x w = y x = z y = w, set_zwyx: z = x w = y y = z x = w,
+    set_wxyz: w = x x = y y = z z = w, set_wxzy: w = x x = y z = z y = w,
+    set_wyxz: w = x y = y x = z z = w, set_wyzx: w = x y = y z = z x = w,
+    set_wzxy: w = x z = y x = z y = w, set_wzyx: w = x z = y y = z x = w,
+});
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Extensions
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Number> Vec2<T> {
+    pub fn extend(self, z: T) -> Vec3<T> {
+        Vec3::new(self.x, self.y, z)
+    }
+
+    // Homogeneous 2d direction
+    pub fn xy0(self) -> Vec3<T> {
+        self.extend(T::zero())
+    }
+
+    // Homogeneous 2d point
+    pub fn xy1(self) -> Vec3<T> {
+        self.extend(T::one())
+    }
+}
+
+impl<T: Number> Vec3<T> {
+    pub fn extend(self, w: T) -> Vec4<T> {
+        Vec4::new(self.x, self.y, self.z, w)
+    }
+
+    pub fn truncate(self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+
+    // Homogeneous 3d direction
+    pub fn xyz0(self) -> Vec4<T> {
+        self.extend(T::zero())
+    }
+
+    // Homogeneous 3d point
+    pub fn xyz1(self) -> Vec4<T> {
+        self.extend(T::one())
+    }
+}
+
+impl<T: Number> Vec4<T> {
+    pub fn truncate(self) -> Vec3<T> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+}
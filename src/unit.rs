@@ -0,0 +1,199 @@
+use super::angle::Rad;
+use super::base::{Float, Number};
+use super::matrix::Mat3;
+use super::quaternion::{EulerRot, Quaternion};
+use super::vector::{FloatVectorOps, Vec2, Vec3, Vec4};
+use std::ops::{Deref, Mul, Neg};
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Traits
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Types with a length that Unit can normalize.
+pub trait Normed: Copy {
+    type Norm: Float;
+
+    fn norm(&self) -> Self::Norm;
+    fn scale_norm(self, factor: Self::Norm) -> Self;
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Unit
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// A value known to have unit length. The only ways in are the checked constructors and
+// new_unchecked, so anything taking a Unit can skip normalizing.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
+pub struct Unit<V> {
+    value: V,
+}
+
+impl<V> Unit<V>
+where
+    V: Normed,
+{
+    // Returns None for zero length or non finite input.
+    pub fn new_normalize(value: V) -> Option<Self> {
+        Self::try_new(value, V::Norm::epsilon())
+    }
+
+    // Returns None unless the length is finite and greater than min_norm.
+    pub fn try_new(value: V, min_norm: V::Norm) -> Option<Self> {
+        let norm = value.norm();
+        if !norm.is_finite() || norm <= min_norm {
+            return None;
+        }
+
+        Some(Self {
+            value: value.scale_norm(V::Norm::one() / norm),
+        })
+    }
+
+    // The caller guarantees the value already has unit length.
+    pub fn new_unchecked(value: V) -> Self {
+        Self { value }
+    }
+
+    // Removes drift accumulated by repeated operations on the inner value.
+    pub fn renormalize(&mut self) {
+        let norm = self.value.norm();
+        self.value = self.value.scale_norm(V::Norm::one() / norm);
+    }
+
+    pub fn into_inner(self) -> V {
+        self.value
+    }
+}
+
+impl<V> Deref for Unit<V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<V> AsRef<V> for Unit<V> {
+    fn as_ref(&self) -> &V {
+        &self.value
+    }
+}
+
+impl<V> Neg for Unit<V>
+where
+    V: Neg<Output = V>,
+{
+    type Output = Unit<V>;
+
+    fn neg(self) -> Self::Output {
+        Unit { value: -self.value }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Normed Impls
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Float> Normed for Vec2<T> {
+    type Norm = T;
+
+    fn norm(&self) -> Self::Norm {
+        self.magnitude()
+    }
+
+    fn scale_norm(self, factor: Self::Norm) -> Self {
+        self * factor
+    }
+}
+
+impl<T: Float> Normed for Vec3<T> {
+    type Norm = T;
+
+    fn norm(&self) -> Self::Norm {
+        self.magnitude()
+    }
+
+    fn scale_norm(self, factor: Self::Norm) -> Self {
+        self * factor
+    }
+}
+
+impl<T: Float> Normed for Vec4<T> {
+    type Norm = T;
+
+    fn norm(&self) -> Self::Norm {
+        self.magnitude()
+    }
+
+    fn scale_norm(self, factor: Self::Norm) -> Self {
+        self * factor
+    }
+}
+
+impl<T: Float> Normed for Quaternion<T> {
+    type Norm = T;
+
+    fn norm(&self) -> Self::Norm {
+        self.magnitude()
+    }
+
+    fn scale_norm(self, factor: Self::Norm) -> Self {
+        self * factor
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Unit Quaternion
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Float> Unit<Quaternion<T>> {
+    pub fn identity() -> Self {
+        Self::new_unchecked(Quaternion::identity())
+    }
+
+    pub fn from_axis_angle(axis: Unit<Vec3<T>>, angle: impl Into<Rad<T>>) -> Self {
+        Self::new_unchecked(Quaternion::from_axis_angle(axis, angle))
+    }
+
+    pub fn from_euler(
+        order: EulerRot,
+        a: impl Into<Rad<T>>,
+        b: impl Into<Rad<T>>,
+        c: impl Into<Rad<T>>,
+    ) -> Self {
+        Self::new_unchecked(Quaternion::from_euler(order, a, b, c))
+    }
+
+    pub fn from_rotation_matrix(mat: Mat3<T>) -> Self {
+        Self::new_unchecked(Quaternion::from_rotation_matrix(mat))
+    }
+
+    pub fn from_rotation_arc(from: Vec3<T>, to: Vec3<T>) -> Self {
+        Self::new_unchecked(Quaternion::from_rotation_arc(from, to))
+    }
+
+    pub fn look_rotation(forward: Vec3<T>, up: Vec3<T>) -> Self {
+        Self::new_unchecked(Quaternion::look_rotation(forward, up))
+    }
+
+    // The conjugate of a unit quaternion is its inverse.
+    pub fn inverse(&self) -> Self {
+        Self::new_unchecked(self.value.conjugate())
+    }
+}
+
+impl<T: Float> Mul for Unit<Quaternion<T>> {
+    type Output = Unit<Quaternion<T>>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Unit::new_unchecked(self.value * rhs.value)
+    }
+}
+
+impl<T: Float> Mul<Vec3<T>> for Unit<Quaternion<T>> {
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: Vec3<T>) -> Self::Output {
+        self.value * rhs
+    }
+}
@@ -1,7 +1,9 @@
-use super::base::Float;
+use super::angle::Rad;
+use super::base::{Float, Number, Signed};
+use super::unit::Unit;
 use std::{
     fmt::Display,
-    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 };
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -9,23 +11,52 @@ use std::{
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub trait VectorOps {
-    type Float;
+    type Scalar;
 
     fn zeroed() -> Self;
     fn add(self, other: Self) -> Self;
     fn sub(self, other: Self) -> Self;
-    fn scale(self, other: Self::Float) -> Self;
-    fn magnitude(self) -> Self::Float;
-    fn sq_magnitude(self) -> Self::Float;
-    fn normalize(self) -> Self;
+    fn scale(self, other: Self::Scalar) -> Self;
+    fn sq_magnitude(self) -> Self::Scalar;
     fn distance(self, other: Self) -> Self;
-    fn dot(self, other: Self) -> Self::Float;
+    fn dot(self, other: Self) -> Self::Scalar;
+}
+
+// The cross product only exists in three dimensions. Vec4 takes it over xyz, for homogeneous
+// direction vectors. Vec2 has perp_dot instead.
+pub trait CrossOps: VectorOps {
     fn cross(self, other: Self) -> Self;
-    fn angle(self, other: Self) -> Self::Float;
-    fn lerp(self, other: Self, frac: Self::Float) -> Self;
-    fn nlerp(self, other: Self, frac: Self::Float) -> Self;
-    fn slerp(self, other: Self, frac: Self::Float) -> Self;
-    fn approx_eq(self, other: Self, within: Self::Float) -> bool;
+}
+
+// Operations that only make sense over real numbers.
+pub trait FloatVectorOps: VectorOps<Scalar: Float> {
+    fn magnitude(self) -> Self::Scalar;
+    // Zero length input gives NaN components. Use one of the checked variants below when the
+    // input may be degenerate.
+    fn normalize(self) -> Self;
+    fn try_normalize(self) -> Option<Self>
+    where
+        Self: Sized;
+    fn normalize_or(self, fallback: Self) -> Self;
+    fn normalize_or_zero(self) -> Self;
+    fn is_normalized(&self) -> bool;
+    // Zero if either vector has zero length.
+    fn angle(self, other: Self) -> Rad<Self::Scalar>;
+    fn lerp(self, other: Self, frac: Self::Scalar) -> Self;
+    fn nlerp(self, other: Self, frac: Self::Scalar) -> Self;
+    fn slerp(self, other: Self, frac: Self::Scalar) -> Self;
+    fn approx_eq(self, other: Self, within: Self::Scalar) -> bool;
+    // The normal is expected to be normalized. Eta is the ratio of the refractive indices, and
+    // refract gives None on total internal reflection.
+    fn reflect(self, normal: Self) -> Self;
+    fn refract(self, normal: Self, eta: Self::Scalar) -> Option<Self>
+    where
+        Self: Sized;
+    // Projecting onto or rejecting from a zero length vector gives NaN components.
+    fn project_onto(self, other: Self) -> Self;
+    fn reject_from(self, other: Self) -> Self;
+    // Self flipped, if needed, to point against the incident vector as seen from the reference.
+    fn faceforward(self, incident: Self, reference: Self) -> Self;
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -34,44 +65,82 @@ pub trait VectorOps {
 
 #[allow(non_snake_case)]
 pub mod Vector {
-    use super::VectorOps;
+    use super::{CrossOps, FloatVectorOps, Rad, VectorOps};
     pub fn add<T: VectorOps>(vector1: T, vector2: T) -> T {
         vector1.add(vector2)
     }
     pub fn sub<T: VectorOps>(vector1: T, vector2: T) -> T {
         vector1.sub(vector2)
     }
-    pub fn scale<T: VectorOps>(vector: T, scalar: <T as VectorOps>::Float) -> T {
+    pub fn scale<T: VectorOps>(vector: T, scalar: <T as VectorOps>::Scalar) -> T {
         vector.scale(scalar)
     }
-    pub fn magnitude<T: VectorOps>(vector: T) -> <T as VectorOps>::Float {
+    pub fn magnitude<T: FloatVectorOps>(vector: T) -> <T as VectorOps>::Scalar {
         vector.magnitude()
     }
-    pub fn sq_magnitude<T: VectorOps>(vector: T) -> <T as VectorOps>::Float {
+    pub fn sq_magnitude<T: VectorOps>(vector: T) -> <T as VectorOps>::Scalar {
         vector.sq_magnitude()
     }
-    pub fn normalize<T: VectorOps>(vector: T) -> T {
+    pub fn normalize<T: FloatVectorOps>(vector: T) -> T {
         vector.normalize()
     }
     pub fn distance<T: VectorOps>(from: T, to: T) -> T {
         from.distance(to)
     }
-    pub fn dot<T: VectorOps>(vector1: T, vector2: T) -> <T as VectorOps>::Float {
+    pub fn dot<T: VectorOps>(vector1: T, vector2: T) -> <T as VectorOps>::Scalar {
         vector1.dot(vector2)
     }
-    pub fn angle<T: VectorOps>(from: T, to: T) -> <T as VectorOps>::Float {
+    pub fn try_normalize<T: FloatVectorOps>(vector: T) -> Option<T> {
+        vector.try_normalize()
+    }
+    pub fn normalize_or<T: FloatVectorOps>(vector: T, fallback: T) -> T {
+        vector.normalize_or(fallback)
+    }
+    pub fn normalize_or_zero<T: FloatVectorOps>(vector: T) -> T {
+        vector.normalize_or_zero()
+    }
+    pub fn is_normalized<T: FloatVectorOps>(vector: T) -> bool {
+        vector.is_normalized()
+    }
+    pub fn cross<T: CrossOps>(vector1: T, vector2: T) -> T {
+        vector1.cross(vector2)
+    }
+    pub fn angle<T: FloatVectorOps>(from: T, to: T) -> Rad<<T as VectorOps>::Scalar> {
         from.angle(to)
     }
-    pub fn lerp<T: VectorOps>(from: T, to: T, frac: <T as VectorOps>::Float) -> T {
+    pub fn lerp<T: FloatVectorOps>(from: T, to: T, frac: <T as VectorOps>::Scalar) -> T {
         from.lerp(to, frac)
     }
-    pub fn nlerp<T: VectorOps>(from: T, to: T, frac: <T as VectorOps>::Float) -> T {
+    pub fn nlerp<T: FloatVectorOps>(from: T, to: T, frac: <T as VectorOps>::Scalar) -> T {
         from.nlerp(to, frac)
     }
-    pub fn slerp<T: VectorOps>(from: T, to: T, frac: <T as VectorOps>::Float) -> T {
+    pub fn slerp<T: FloatVectorOps>(from: T, to: T, frac: <T as VectorOps>::Scalar) -> T {
         from.slerp(to, frac)
     }
-    pub fn approx_eq<T: VectorOps>(vector1: T, other: T, within: <T as VectorOps>::Float) -> bool {
+    pub fn reflect<T: FloatVectorOps>(vector: T, normal: T) -> T {
+        vector.reflect(normal)
+    }
+    pub fn refract<T: FloatVectorOps>(
+        vector: T,
+        normal: T,
+        eta: <T as VectorOps>::Scalar,
+    ) -> Option<T> {
+        vector.refract(normal, eta)
+    }
+    pub fn project_onto<T: FloatVectorOps>(vector: T, other: T) -> T {
+        vector.project_onto(other)
+    }
+    pub fn reject_from<T: FloatVectorOps>(vector: T, other: T) -> T {
+        vector.reject_from(other)
+    }
+    pub fn faceforward<T: FloatVectorOps>(vector: T, incident: T, reference: T) -> T {
+        vector.faceforward(incident, reference)
+    }
+    pub fn approx_eq<T: FloatVectorOps>(
+        vector1: T,
+        other: T,
+        within: <T as VectorOps>::Scalar,
+    ) -> bool {
         vector1.approx_eq(other, within)
     }
 }
@@ -81,14 +150,14 @@ pub mod Vector {
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
-pub struct Vec2<T: Float> {
+pub struct Vec2<T: Number> {
     pub x: T,
     pub y: T,
 }
 
 impl<T> Vec2<T>
 where
-    T: Float,
+    T: Number,
 {
     pub fn new(x: T, y: T) -> Self {
         Self { x, y }
@@ -109,16 +178,48 @@ where
     }
 }
 
-impl<T> VectorOps for Vec2<T>
+impl<T> Vec2<T>
+where
+    T: Signed,
+{
+    // Rotated a quarter turn counter clockwise.
+    pub fn perp(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    // z component of the 3D cross product of the two vectors extended with z = 0. Positive when
+    // other is counter clockwise from self.
+    pub fn perp_dot(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T> Vec2<T>
 where
     T: Float,
+{
+    pub fn x_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_x())
+    }
+
+    pub fn y_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_y())
+    }
+}
+
+impl<T> VectorOps for Vec2<T>
+where
+    T: Number,
     T: Add<Output = T>,
     T: Sub<Output = T>,
     T: Mul<Output = T>,
     T: Div<Output = T>,
     T: Copy,
 {
-    type Float = T;
+    type Scalar = T;
 
     fn zeroed() -> Self {
         Vec2::new(T::zero(), T::zero())
@@ -136,68 +237,23 @@ where
         self * other
     }
 
-    fn magnitude(self) -> Self::Float {
-        Float::sqrt((self.x * self.x) + (self.y * self.y))
-    }
-
-    fn sq_magnitude(self) -> Self::Float {
+    fn sq_magnitude(self) -> Self::Scalar {
         (self.x * self.x) + (self.y * self.y)
     }
 
-    fn normalize(self) -> Self {
-        let mag = self.magnitude();
-        Self {
-            x: self.x / mag,
-            y: self.y / mag,
-        }
-    }
-
     fn distance(self, other: Self) -> Self {
         other - self
     }
 
-    fn dot(self, other: Self) -> Self::Float {
+    fn dot(self, other: Self) -> Self::Scalar {
         (self.x * other.x) + (self.y * other.y)
     }
-
-    fn cross(self, _other: Self) -> Self {
-        todo!()
-    }
-
-    fn angle(self, other: Self) -> Self::Float {
-        Float::acos(self.dot(other) / (self.magnitude() * other.magnitude()))
-    }
-
-    fn lerp(self, other: Self, frac: Self::Float) -> Self {
-        self + self.distance(other) * frac
-    }
-
-    fn nlerp(self, other: Self, frac: Self::Float) -> Self {
-        self.lerp(other, frac).normalize()
-    }
-
-    fn slerp(self, other: Self, frac: Self::Float) -> Self {
-        let dot = self.dot(other);
-        let dot = dot.clamp(Float::neg_one(), Float::one());
-
-        let theta = dot.acos();
-        let theta = theta * frac;
-
-        let relative = (other - (self * dot)).normalize();
-        let (sin, cos) = theta.sin_cos();
-
-        (self * cos) + (relative * sin)
-    }
-
-    fn approx_eq(self, other: Self, within: Self::Float) -> bool {
-        Float::aprox_eq(self.x, other.x, within) && Float::aprox_eq(self.y, other.y, within)
-    }
 }
 
 impl<T> Neg for Vec2<T>
 where
     T: Neg<Output = T>,
-    T: Float,
+    T: Number,
 {
     type Output = Vec2<T>;
 
@@ -211,7 +267,7 @@ where
 
 impl<T> Add for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
 {
     type Output = Vec2<T>;
@@ -226,7 +282,7 @@ where
 
 impl<T> Sub for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
 {
     type Output = Vec2<T>;
@@ -241,7 +297,7 @@ where
 
 impl<T> Mul<T> for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -257,7 +313,7 @@ where
 
 impl<T> AddAssign for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
     T: Copy,
 {
@@ -268,7 +324,7 @@ where
 
 impl<T> SubAssign for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
     T: Copy,
 {
@@ -279,7 +335,7 @@ where
 
 impl<T> MulAssign<T> for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -290,7 +346,7 @@ where
 
 impl<T> Display for Vec2<T>
 where
-    T: Float,
+    T: Number,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "vec2({}, {})", self.x, self.y)
@@ -302,7 +358,7 @@ where
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
-pub struct Vec3<T: Float> {
+pub struct Vec3<T: Number> {
     pub x: T,
     pub y: T,
     pub z: T,
@@ -310,7 +366,7 @@ pub struct Vec3<T: Float> {
 
 impl<T> Vec3<T>
 where
-    T: Float,
+    T: Number,
 {
     pub fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
@@ -341,16 +397,56 @@ where
     }
 }
 
-impl<T> VectorOps for Vec3<T>
+impl<T> Vec3<T>
 where
     T: Float,
+{
+    pub fn x_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_x())
+    }
+
+    pub fn y_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_y())
+    }
+
+    pub fn z_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_z())
+    }
+
+    // Two unit vectors perpendicular to self and to each other, which is expected to be
+    // normalized. Branchless, from Duff et al. "Building an Orthonormal Basis, Revisited".
+    pub fn any_orthonormal_pair(self) -> (Self, Self) {
+        let sign = T::one().copysign(self.z);
+        let a = T::neg_one() / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Vec3::new(
+                T::one() + sign * self.x * self.x * a,
+                sign * b,
+                -sign * self.x,
+            ),
+            Vec3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    // A right handed basis with self, normalized, as the third axis. None for zero length input.
+    pub fn any_orthonormal_basis(self) -> Option<(Self, Self, Self)> {
+        let normal = self.try_normalize()?;
+        let (tangent, bitangent) = normal.any_orthonormal_pair();
+        Some((tangent, bitangent, normal))
+    }
+}
+
+impl<T> VectorOps for Vec3<T>
+where
+    T: Number,
     T: Add<Output = T>,
     T: Sub<Output = T>,
     T: Mul<Output = T>,
     T: Div<Output = T>,
     T: Copy,
 {
-    type Float = T;
+    type Scalar = T;
 
     fn zeroed() -> Self {
         Vec3::new(T::zero(), T::zero(), T::zero())
@@ -364,35 +460,27 @@ where
         self - other
     }
 
-    fn scale(self, other: Self::Float) -> Self {
+    fn scale(self, other: Self::Scalar) -> Self {
         self * other
     }
 
-    fn magnitude(self) -> Self::Float {
-        Float::sqrt((self.x * self.x) + (self.y * self.y) + (self.z * self.z))
-    }
-
-    fn sq_magnitude(self) -> Self::Float {
+    fn sq_magnitude(self) -> Self::Scalar {
         (self.x * self.x) + (self.y * self.y) + (self.z * self.z)
     }
 
-    fn normalize(self) -> Self {
-        let mag = self.magnitude();
-        Self {
-            x: self.x / mag,
-            y: self.y / mag,
-            z: self.z / mag,
-        }
-    }
-
     fn distance(self, other: Self) -> Self {
         other - self
     }
 
-    fn dot(self, other: Self) -> Self::Float {
+    fn dot(self, other: Self) -> Self::Scalar {
         (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
     }
+}
 
+impl<T> CrossOps for Vec3<T>
+where
+    T: Signed,
+{
     fn cross(self, other: Self) -> Self {
         Vec3::new(
             self.y * other.z - self.z * other.y,
@@ -400,43 +488,12 @@ where
             self.x * other.y - self.y * other.x,
         )
     }
-
-    fn angle(self, other: Self) -> Self::Float {
-        Float::acos(self.dot(other) / (self.magnitude() * other.magnitude()))
-    }
-
-    fn lerp(self, other: Self, frac: Self::Float) -> Self {
-        self + self.distance(other) * frac
-    }
-
-    fn nlerp(self, other: Self, frac: Self::Float) -> Self {
-        self.lerp(other, frac).normalize()
-    }
-
-    fn slerp(self, other: Self, frac: Self::Float) -> Self {
-        let dot = self.dot(other);
-        let dot = dot.clamp(Float::neg_one(), Float::one());
-
-        let theta = dot.acos();
-        let theta = theta * frac;
-
-        let relative = (other - (self * dot)).normalize();
-        let (sin, cos) = theta.sin_cos();
-
-        (self * cos) + (relative * sin)
-    }
-
-    fn approx_eq(self, other: Self, within: Self::Float) -> bool {
-        Float::aprox_eq(self.x, other.x, within)
-            && Float::aprox_eq(self.y, other.y, within)
-            && Float::aprox_eq(self.z, other.z, within)
-    }
 }
 
 impl<T> Neg for Vec3<T>
 where
     T: Neg<Output = T>,
-    T: Float,
+    T: Number,
 {
     type Output = Vec3<T>;
 
@@ -451,7 +508,7 @@ where
 
 impl<T> Add for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
 {
     type Output = Vec3<T>;
@@ -467,7 +524,7 @@ where
 
 impl<T> Sub for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
 {
     type Output = Vec3<T>;
@@ -483,7 +540,7 @@ where
 
 impl<T> Mul<T> for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -500,7 +557,7 @@ where
 
 impl<T> AddAssign for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
     T: Copy,
 {
@@ -511,7 +568,7 @@ where
 
 impl<T> SubAssign for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
     T: Copy,
 {
@@ -522,7 +579,7 @@ where
 
 impl<T> MulAssign<T> for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -533,7 +590,7 @@ where
 
 impl<T> Display for Vec3<T>
 where
-    T: Float,
+    T: Number,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "vec3({}, {}, {})", self.x, self.y, self.z)
@@ -545,7 +602,7 @@ where
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Default, Debug, Clone, Copy, Hash, PartialEq, PartialOrd)]
-pub struct Vec4<T: Float> {
+pub struct Vec4<T: Number> {
     pub x: T,
     pub y: T,
     pub z: T,
@@ -554,7 +611,7 @@ pub struct Vec4<T: Float> {
 
 impl<T> Vec4<T>
 where
-    T: Float,
+    T: Number,
 {
     pub fn new(x: T, y: T, z: T, w: T) -> Self {
         Self { x, y, z, w }
@@ -597,16 +654,37 @@ where
     }
 }
 
-impl<T> VectorOps for Vec4<T>
+impl<T> Vec4<T>
 where
     T: Float,
+{
+    pub fn x_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_x())
+    }
+
+    pub fn y_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_y())
+    }
+
+    pub fn z_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_z())
+    }
+
+    pub fn w_axis() -> Unit<Self> {
+        Unit::new_unchecked(Self::unit_w())
+    }
+}
+
+impl<T> VectorOps for Vec4<T>
+where
+    T: Number,
     T: Add<Output = T>,
     T: Sub<Output = T>,
     T: Mul<Output = T>,
     T: Div<Output = T>,
     T: Copy,
 {
-    type Float = T;
+    type Scalar = T;
 
     fn zeroed() -> Self {
         Vec4::new(T::zero(), T::zero(), T::zero(), T::zero())
@@ -620,77 +698,42 @@ where
         self - other
     }
 
-    fn scale(self, other: Self::Float) -> Self {
+    fn scale(self, other: Self::Scalar) -> Self {
         self * other
     }
 
-    fn magnitude(self) -> Self::Float {
-        Float::sqrt((self.x * self.x) + (self.y * self.y) + (self.z * self.z) + (self.w * self.w))
-    }
-
-    fn sq_magnitude(self) -> Self::Float {
+    fn sq_magnitude(self) -> Self::Scalar {
         (self.x * self.x) + (self.y * self.y) + (self.z * self.z) + (self.w * self.w)
     }
 
-    fn normalize(self) -> Self {
-        let mag = self.magnitude();
-        Self {
-            x: self.x / mag,
-            y: self.y / mag,
-            z: self.z / mag,
-            w: self.w / mag,
-        }
-    }
-
     fn distance(self, other: Self) -> Self {
         other - self
     }
 
-    fn dot(self, other: Self) -> Self::Float {
+    fn dot(self, other: Self) -> Self::Scalar {
         (self.x * other.x) + (self.y * other.y) + (self.z * other.z) + (self.w * other.w)
     }
+}
 
-    fn cross(self, _other: Self) -> Self {
-        todo!()
-    }
-
-    fn angle(self, other: Self) -> Self::Float {
-        Float::acos(self.dot(other) / (self.magnitude() * other.magnitude()))
-    }
-
-    fn lerp(self, other: Self, frac: Self::Float) -> Self {
-        self + self.distance(other) * frac
-    }
-
-    fn nlerp(self, other: Self, frac: Self::Float) -> Self {
-        self.lerp(other, frac).normalize()
-    }
-
-    fn slerp(self, other: Self, frac: Self::Float) -> Self {
-        let dot = self.dot(other);
-        let dot = dot.clamp(Float::neg_one(), Float::one());
-
-        let theta = dot.acos();
-        let theta = theta * frac;
-
-        let relative = (other - (self * dot)).normalize();
-        let (sin, cos) = theta.sin_cos();
-
-        (self * cos) + (relative * sin)
-    }
-
-    fn approx_eq(self, other: Self, within: Self::Float) -> bool {
-        Float::aprox_eq(self.x, other.x, within)
-            && Float::aprox_eq(self.y, other.y, within)
-            && Float::aprox_eq(self.z, other.z, within)
-            && Float::aprox_eq(self.w, other.w, within)
+// w is ignored on input and zero on output.
+impl<T> CrossOps for Vec4<T>
+where
+    T: Signed,
+{
+    fn cross(self, other: Self) -> Self {
+        Vec4::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+            T::zero(),
+        )
     }
 }
 
 impl<T> Neg for Vec4<T>
 where
     T: Neg<Output = T>,
-    T: Float,
+    T: Number,
 {
     type Output = Vec4<T>;
 
@@ -706,7 +749,7 @@ where
 
 impl<T> Add for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
 {
     type Output = Vec4<T>;
@@ -723,7 +766,7 @@ where
 
 impl<T> Sub for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
 {
     type Output = Vec4<T>;
@@ -740,7 +783,7 @@ where
 
 impl<T> Mul<T> for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -758,7 +801,7 @@ where
 
 impl<T> AddAssign for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Add<Output = T>,
     T: Copy,
 {
@@ -769,7 +812,7 @@ where
 
 impl<T> SubAssign for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Sub<Output = T>,
     T: Copy,
 {
@@ -780,7 +823,7 @@ where
 
 impl<T> MulAssign<T> for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Mul<Output = T>,
     T: Copy,
 {
@@ -791,20 +834,375 @@ where
 
 impl<T> Display for Vec4<T>
 where
-    T: Float,
+    T: Number,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "vec4({}, {}, {}, {})", self.x, self.y, self.z, self.w)
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Component-wise
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_componentwise {
+    ($vec:ident { $first:ident $(, $field:ident)* }) => {
+        impl<T: Number> Mul for $vec<T> {
+            type Output = $vec<T>;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                $vec {
+                    $first: self.$first * rhs.$first,
+                    $($field: self.$field * rhs.$field,)*
+                }
+            }
+        }
+
+        impl<T: Number> Div for $vec<T> {
+            type Output = $vec<T>;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                $vec {
+                    $first: self.$first / rhs.$first,
+                    $($field: self.$field / rhs.$field,)*
+                }
+            }
+        }
+
+        impl<T: Number> Div<T> for $vec<T> {
+            type Output = $vec<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $vec {
+                    $first: self.$first / rhs,
+                    $($field: self.$field / rhs,)*
+                }
+            }
+        }
+
+        impl<T: Number> Rem for $vec<T> {
+            type Output = $vec<T>;
+
+            fn rem(self, rhs: Self) -> Self::Output {
+                $vec {
+                    $first: self.$first % rhs.$first,
+                    $($field: self.$field % rhs.$field,)*
+                }
+            }
+        }
+
+        impl<T: Number> Rem<T> for $vec<T> {
+            type Output = $vec<T>;
+
+            fn rem(self, rhs: T) -> Self::Output {
+                $vec {
+                    $first: self.$first % rhs,
+                    $($field: self.$field % rhs,)*
+                }
+            }
+        }
+
+        impl<T: Number> MulAssign for $vec<T> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<T: Number> DivAssign for $vec<T> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<T: Number> DivAssign<T> for $vec<T> {
+            fn div_assign(&mut self, rhs: T) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<T: Number> RemAssign for $vec<T> {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+
+        impl<T: Number> RemAssign<T> for $vec<T> {
+            fn rem_assign(&mut self, rhs: T) {
+                *self = *self % rhs;
+            }
+        }
+
+        impl<T> $vec<T>
+        where
+            T: Number,
+        {
+            // Takes other's component whenever the comparison fails, including against NaN.
+            pub fn min(self, other: Self) -> Self {
+                $vec {
+                    $first: if self.$first < other.$first { self.$first } else { other.$first },
+                    $($field: if self.$field < other.$field { self.$field } else { other.$field },)*
+                }
+            }
+
+            pub fn max(self, other: Self) -> Self {
+                $vec {
+                    $first: if self.$first > other.$first { self.$first } else { other.$first },
+                    $($field: if self.$field > other.$field { self.$field } else { other.$field },)*
+                }
+            }
+
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+
+            pub fn min_element(self) -> T {
+                let mut min = self.$first;
+                $(if self.$field < min {
+                    min = self.$field;
+                })*
+                min
+            }
+
+            pub fn max_element(self) -> T {
+                let mut max = self.$first;
+                $(if self.$field > max {
+                    max = self.$field;
+                })*
+                max
+            }
+
+            pub fn sum(self) -> T {
+                self.$first $(+ self.$field)*
+            }
+
+            pub fn product(self) -> T {
+                self.$first $(* self.$field)*
+            }
+        }
+
+        impl<T> $vec<T>
+        where
+            T: Signed,
+        {
+            pub fn abs(self) -> Self {
+                $vec {
+                    $first: self.$first.abs(),
+                    $($field: self.$field.abs(),)*
+                }
+            }
+
+            pub fn signum(self) -> Self {
+                $vec {
+                    $first: self.$first.signum(),
+                    $($field: self.$field.signum(),)*
+                }
+            }
+        }
+
+        impl<T> $vec<T>
+        where
+            T: Float,
+        {
+            pub fn floor(self) -> Self {
+                $vec {
+                    $first: self.$first.floor(),
+                    $($field: self.$field.floor(),)*
+                }
+            }
+
+            pub fn ceil(self) -> Self {
+                $vec {
+                    $first: self.$first.ceil(),
+                    $($field: self.$field.ceil(),)*
+                }
+            }
+
+            pub fn round(self) -> Self {
+                $vec {
+                    $first: self.$first.round(),
+                    $($field: self.$field.round(),)*
+                }
+            }
+
+            pub fn fract(self) -> Self {
+                $vec {
+                    $first: self.$first.fract(),
+                    $($field: self.$field.fract(),)*
+                }
+            }
+
+            pub fn recip(self) -> Self {
+                $vec {
+                    $first: self.$first.recip(),
+                    $($field: self.$field.recip(),)*
+                }
+            }
+        }
+    };
+}
+
+impl_componentwise!(Vec2 { x, y });
+impl_componentwise!(Vec3 { x, y, z });
+impl_componentwise!(Vec4 { x, y, z, w });
+
+// The float operations only differ in which fields they touch.
+macro_rules! impl_float_vector_ops {
+    ($vec:ident { $first:ident $(, $field:ident)* }) => {
+        impl<T> FloatVectorOps for $vec<T>
+        where
+            T: Float,
+            T: Add<Output = T>,
+            T: Sub<Output = T>,
+            T: Mul<Output = T>,
+            T: Div<Output = T>,
+            T: Copy,
+        {
+            fn magnitude(self) -> Self::Scalar {
+                Float::sqrt(self.sq_magnitude())
+            }
+
+            fn normalize(self) -> Self {
+                let mag = self.magnitude();
+                $vec {
+                    $first: self.$first / mag,
+                    $($field: self.$field / mag,)*
+                }
+            }
+
+            fn try_normalize(self) -> Option<Self> {
+                let recip = T::one() / self.magnitude();
+                if recip.is_finite() && recip > T::zero() {
+                    Some(self * recip)
+                } else {
+                    None
+                }
+            }
+
+            fn normalize_or(self, fallback: Self) -> Self {
+                self.try_normalize().unwrap_or(fallback)
+            }
+
+            fn normalize_or_zero(self) -> Self {
+                self.normalize_or(Self::zeroed())
+            }
+
+            fn is_normalized(&self) -> bool {
+                (self.sq_magnitude() - T::one()).abs() <= T::epsilon().sqrt()
+            }
+
+            fn angle(self, other: Self) -> Rad<Self::Scalar> {
+                let lengths = self.magnitude() * other.magnitude();
+                if lengths == T::zero() {
+                    return Rad(T::zero());
+                }
+
+                // Rounding can push the cosine of (anti)parallel vectors just past +-1.
+                let cos = (self.dot(other) / lengths).clamp(T::neg_one(), T::one());
+                Rad(cos.acos())
+            }
+
+            fn lerp(self, other: Self, frac: Self::Scalar) -> Self {
+                self + self.distance(other) * frac
+            }
+
+            fn nlerp(self, other: Self, frac: Self::Scalar) -> Self {
+                self.lerp(other, frac).normalize()
+            }
+
+            fn slerp(self, other: Self, frac: Self::Scalar) -> Self {
+                let dot = self.dot(other);
+                let dot = dot.clamp(T::neg_one(), T::one());
+
+                let theta = dot.acos();
+                let theta = theta * frac;
+
+                let relative = (other - (self * dot)).normalize();
+                let (sin, cos) = theta.sin_cos();
+
+                (self * cos) + (relative * sin)
+            }
+
+            fn approx_eq(self, other: Self, within: Self::Scalar) -> bool {
+                Float::aprox_eq(self.$first, other.$first, within)
+                    $(&& Float::aprox_eq(self.$field, other.$field, within))*
+            }
+
+            fn reflect(self, normal: Self) -> Self {
+                self - normal * (T::two() * self.dot(normal))
+            }
+
+            fn refract(self, normal: Self, eta: Self::Scalar) -> Option<Self> {
+                let cos = normal.dot(self);
+                let k = T::one() - eta * eta * (T::one() - cos * cos);
+                if k < T::zero() {
+                    return None;
+                }
+                Some(self * eta - normal * (eta * cos + k.sqrt()))
+            }
+
+            fn project_onto(self, other: Self) -> Self {
+                other * (self.dot(other) / other.sq_magnitude())
+            }
+
+            fn reject_from(self, other: Self) -> Self {
+                self - self.project_onto(other)
+            }
+
+            fn faceforward(self, incident: Self, reference: Self) -> Self {
+                if reference.dot(incident) < T::zero() {
+                    self
+                } else {
+                    -self
+                }
+            }
+        }
+    };
+}
+
+impl_float_vector_ops!(Vec2 { x, y });
+impl_float_vector_ops!(Vec3 { x, y, z });
+impl_float_vector_ops!(Vec4 { x, y, z, w });
+
+// Scalar on the left. Coherence rules out a generic impl, so each scalar type gets its own.
+macro_rules! impl_scalar_mul {
+    ($($scalar:ty),+) => {
+        $(
+            impl Mul<Vec2<$scalar>> for $scalar {
+                type Output = Vec2<$scalar>;
+
+                fn mul(self, rhs: Vec2<$scalar>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl Mul<Vec3<$scalar>> for $scalar {
+                type Output = Vec3<$scalar>;
+
+                fn mul(self, rhs: Vec3<$scalar>) -> Self::Output {
+                    rhs * self
+                }
+            }
+
+            impl Mul<Vec4<$scalar>> for $scalar {
+                type Output = Vec4<$scalar>;
+
+                fn mul(self, rhs: Vec4<$scalar>) -> Self::Output {
+                    rhs * self
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_mul!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Vec2 Conversions
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl<T> From<[T; 2]> for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -818,7 +1216,7 @@ where
 
 impl<T> From<(T, T)> for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -832,7 +1230,7 @@ where
 
 impl<T> From<Vec3<T>> for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
 {
     fn from(value: Vec3<T>) -> Self {
@@ -845,7 +1243,7 @@ where
 
 impl<T> From<Vec4<T>> for Vec2<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
 {
     fn from(value: Vec4<T>) -> Self {
@@ -862,7 +1260,7 @@ where
 
 impl<T> From<[T; 3]> for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -877,7 +1275,7 @@ where
 
 impl<T> From<(T, T, T)> for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -892,7 +1290,7 @@ where
 
 impl<T> From<Vec2<T>> for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
 {
     fn from(value: Vec2<T>) -> Self {
@@ -906,7 +1304,7 @@ where
 
 impl<T> From<Vec4<T>> for Vec3<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
 {
     fn from(value: Vec4<T>) -> Self {
@@ -924,7 +1322,7 @@ where
 
 impl<T> From<[T; 4]> for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -940,7 +1338,7 @@ where
 
 impl<T> From<(T, T, T, T)> for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Copy,
     T: Clone,
 {
@@ -956,7 +1354,7 @@ where
 
 impl<T> From<Vec2<T>> for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
 {
     fn from(value: Vec2<T>) -> Self {
@@ -971,7 +1369,7 @@ where
 
 impl<T> From<Vec3<T>> for Vec4<T>
 where
-    T: Float,
+    T: Number,
     T: Default,
 {
     fn from(value: Vec3<T>) -> Self {